@@ -0,0 +1,230 @@
+//! compares the round trip time of requests between the previous listener design
+//! (blocking listener, a new runtime per response, a new connection per request)
+//! and the current client
+//!
+//! run with `cargo bench --bench listener`
+
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use libccanvas::{
+    bindings::{
+        encode_frame, Discriminator, Request, RequestContent, RequestIds, Subscription,
+        PROTOCOL_VERSION,
+    },
+    client::{Client, ClientConfig, DEFAULT_REQUEST_TIMEOUT},
+};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot};
+
+const ROUND_TRIPS: u32 = 2000;
+
+fn main() {
+    let dir = std::env::temp_dir().join(format!("libccanvas-bench-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let legacy = runtime.block_on(legacy_roundtrips(&dir.join("legacy")));
+    report("legacy listener", legacy);
+
+    let current = runtime.block_on(current_roundtrips(&dir.join("current")));
+    report("tokio listener", current);
+
+    // the legacy listener blocks forever, so dont wait for it
+    runtime.shutdown_background();
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+fn report(label: &str, elapsed: Duration) {
+    println!(
+        "{label:>16}: {ROUND_TRIPS} round trips in {:.2?} ({:.1?} each)",
+        elapsed,
+        elapsed / ROUND_TRIPS
+    );
+}
+
+/// a canvas that answers every request with a success
+///
+/// reads any number of requests from each connection,
+/// either as length delimited frames or as plain concatenated json
+fn fake_canvas(request_socket: &Path, framed: bool) {
+    let listener = UnixListener::bind(request_socket).unwrap();
+    let listener_socket: Arc<Mutex<Option<PathBuf>>> = Arc::default();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => continue,
+            };
+            let listener_socket = listener_socket.clone();
+
+            std::thread::spawn(move || {
+                let mut unframed =
+                    serde_json::Deserializer::from_reader(stream.try_clone().unwrap())
+                        .into_iter::<Value>();
+                let mut stream = stream;
+
+                loop {
+                    let req = if framed {
+                        read_frame(&mut stream)
+                    } else {
+                        unframed.next().and_then(Result::ok)
+                    };
+                    let req = match req {
+                        Some(req) => req,
+                        None => break,
+                    };
+
+                    let content = if req["content"]["type"] == "set socket" {
+                        *listener_socket.lock().unwrap() =
+                            serde_json::from_value(req["content"]["path"].clone()).ok();
+                        json!({ "type": "listener set" })
+                    } else {
+                        json!({ "type": "subscribe added" })
+                    };
+
+                    let path = match listener_socket.lock().unwrap().clone() {
+                        Some(path) => path,
+                        None => continue,
+                    };
+                    let res = json!({
+                        "content": { "type": "success", "content": content },
+                        "id": 0,
+                        "request": req["id"],
+                    });
+                    let bytes = if framed {
+                        encode_frame(&res).unwrap()
+                    } else {
+                        res.to_string().into_bytes()
+                    };
+                    if let Ok(mut stream) = UnixStream::connect(path) {
+                        let _ = stream.write_all(&bytes);
+                    }
+                }
+            });
+        }
+    });
+}
+
+fn read_frame(stream: &mut UnixStream) -> Option<Value> {
+    let mut header = [0; 4];
+    stream.read_exact(&mut header).ok()?;
+    let mut payload = vec![0; u32::from_be_bytes(header) as usize];
+    stream.read_exact(&mut payload).ok()?;
+    serde_json::from_slice(&payload).ok()
+}
+
+fn subscribe_request(ids: &RequestIds) -> Request {
+    Request::new(
+        Discriminator::default(),
+        RequestContent::Subscribe {
+            channel: Subscription::AllKeyPresses,
+            priority: None,
+            component: None,
+        },
+        ids,
+    )
+}
+
+async fn current_roundtrips(dir: &Path) -> Duration {
+    std::fs::create_dir_all(dir).unwrap();
+    let request_socket = dir.join("requests.sock");
+    fake_canvas(&request_socket, true);
+
+    let client = Client::new(ClientConfig {
+        listener_socket: dir.join("listen.sock"),
+        request_socket,
+        request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+    })
+    .await
+    .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..ROUND_TRIPS {
+        client.subscribe(Subscription::AllKeyPresses).await.unwrap();
+    }
+    start.elapsed()
+}
+
+/// the previous design of the client, reduced to what is needed to confirm requests
+async fn legacy_roundtrips(dir: &Path) -> Duration {
+    std::fs::create_dir_all(dir).unwrap();
+    let request_socket = dir.join("requests.sock");
+    let listener_socket = dir.join("listen.sock");
+    fake_canvas(&request_socket, false);
+
+    type Confirms = Arc<tokio::sync::Mutex<HashMap<u32, oneshot::Sender<Value>>>>;
+    let req_confirms: Confirms = Arc::default();
+    let listener = UnixListener::bind(&listener_socket).unwrap();
+    let ids = RequestIds::new();
+
+    let set_socket = Request::new(
+        Discriminator::default(),
+        RequestContent::SetSocket {
+            path: listener_socket,
+            encodings: Vec::new(),
+            protocol: PROTOCOL_VERSION,
+            capabilities: Vec::new(),
+        },
+        &ids,
+    );
+    UnixStream::connect(&request_socket)
+        .unwrap()
+        .write_all(serde_json::to_vec(&set_socket).unwrap().as_slice())
+        .unwrap();
+
+    {
+        let req_confirms = req_confirms.clone();
+        tokio::task::spawn_blocking(move || {
+            for stream in listener.incoming() {
+                let mut msg = String::new();
+                if stream.unwrap().read_to_string(&mut msg).is_err() {
+                    continue;
+                }
+                let res: Value = serde_json::from_str(&msg).unwrap();
+                let request = match res["request"].as_u64() {
+                    Some(request) => request as u32,
+                    None => continue,
+                };
+                if let Some(entry) = tokio::runtime::Runtime::new()
+                    .unwrap()
+                    .block_on(req_confirms.lock())
+                    .remove(&request)
+                {
+                    let _ = entry.send(res);
+                }
+            }
+        });
+    }
+
+    let (outbound_send, mut outbound_recv) = mpsc::unbounded_channel::<Request>();
+    tokio::spawn(async move {
+        while let Some(req) = outbound_recv.recv().await {
+            let request_socket = request_socket.clone();
+            tokio::task::spawn_blocking(move || {
+                UnixStream::connect(request_socket)
+                    .unwrap()
+                    .write_all(serde_json::to_vec(&req).unwrap().as_slice())
+                    .unwrap();
+            });
+        }
+    });
+
+    let start = Instant::now();
+    for _ in 0..ROUND_TRIPS {
+        let req = subscribe_request(&ids);
+        let (tx, rx) = oneshot::channel();
+        req_confirms.lock().await.insert(req.id(), tx);
+        outbound_send.send(req).unwrap();
+        rx.await.unwrap();
+    }
+    start.elapsed()
+}
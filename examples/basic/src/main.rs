@@ -3,11 +3,11 @@ use libccanvas::{bindings::*, client::*};
 #[tokio::main]
 async fn main() {
     // creates the client using the default configuration
-    let client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
 
     // subscribe to all key presses
     // now client.recv() will include key press events
-    client.subscribe(Subscription::AllKeyPresses).await;
+    client.subscribe(Subscription::AllKeyPresses).await.unwrap();
 
     // this is basically a forever loop
     // it always waits until the next event
@@ -17,7 +17,7 @@ async fn main() {
             EventVariant::Key(key) if key.code == KeyCode::Char('q') => {
                 // now that the client is clear, we can send a client.exit event
                 // to make it exit
-                client.exit().await;
+                client.exit().await.unwrap();
 
                 // realistically, the code will never get to this point
                 // as it will be killed automatically as the client exits
@@ -1,4 +1,7 @@
-use libccanvas::{bindings::Colour, client::Client};
+use libccanvas::{
+    bindings::{Cell, Colour},
+    client::{Client, Surface},
+};
 
 pub struct Canvas {
     current: [[Rgb; 20]; 20],
@@ -17,21 +20,20 @@ impl Default for Canvas {
 impl Canvas {
     // render current state
     pub fn render(&self, client: &mut Client) {
+        self.draw(&mut client.region(0, 1, 40, 20));
+    }
+
+    // draw current state inside a region, each pixel is two characters wide
+    // so it looks roughly square
+    fn draw(&self, surface: &mut impl Surface) {
         for (y, row) in self.current.iter().enumerate() {
             for (x, cell) in row.iter().enumerate() {
-                client.setcharcoloured(
+                surface.fill(
                     2 * x as u32,
-                    y as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    (*cell).into(),
-                );
-                client.setcharcoloured(
-                    2 * x as u32 + 1,
-                    y as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    (*cell).into(),
+                    y as u32,
+                    2,
+                    1,
+                    Cell::coloured(' ', Colour::Reset, (*cell).into()),
                 );
             }
         }
@@ -43,9 +45,9 @@ impl Canvas {
         let y = mouse_y.min(19);
 
         self.current[y as usize][x as usize] = rgb;
-        client.setcharcoloured(x * 2, y + 1, ' ', Colour::Reset, rgb.into());
-        client.setcharcoloured(x * 2 + 1, y + 1, ' ', Colour::Reset, rgb.into());
-        client.renderall().await;
+        // only the changed pixel is sent
+        self.render(client);
+        client.renderall().await.unwrap();
     }
 
     // push new state
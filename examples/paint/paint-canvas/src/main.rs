@@ -1,12 +1,12 @@
 use ccanvas_paint_canvas::*;
 use libccanvas::{
     bindings::{EventVariant, KeyCode, KeyEvent, KeyModifier, MouseType, Subscription},
-    client::Client,
+    client::{Client, ClientConfig},
 };
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
 
     // subscribe to these events
     client
@@ -21,7 +21,8 @@ async fn main() {
                 code: KeyCode::Char('z'),
             }),
         ])
-        .await;
+        .await
+        .unwrap();
 
     // create a new blank canvas and a colour picker
     let mut canvas = Canvas::default();
@@ -39,14 +40,16 @@ async fn main() {
             }) => {
                 if mouse_mode != Some(MouseMode::Paint) && canvas.pop() {
                     canvas.render(&mut client);
-                    client.renderall().await;
+                    client.renderall().await.unwrap();
                 }
             }
             // when current space is focused, then render current state
+            // other spaces have drawn over it, so everything is sent again
             EventVariant::Focused => {
+                client.invalidate();
                 canvas.render(&mut client);
                 picker.render(&mut client);
-                client.renderall().await;
+                client.renderall().await.unwrap();
             }
             EventVariant::Unfocused => mouse_mode = None,
             EventVariant::Mouse(mouse) => match mouse.mousetype {
@@ -1,4 +1,7 @@
-use libccanvas::{bindings::Colour, client::Client};
+use libccanvas::{
+    bindings::{Cell, Colour},
+    client::{Client, Surface},
+};
 
 use crate::Rgb;
 
@@ -24,93 +27,66 @@ impl Picker {
     pub async fn set_red(&mut self, value: u8, client: &mut Client) {
         self.red_step = value;
         self.render(client);
-        client.renderall().await;
+        client.renderall().await.unwrap();
     }
 
     pub async fn set_green(&mut self, value: u8, client: &mut Client) {
         self.green_step = value;
         self.render(client);
-        client.renderall().await;
+        client.renderall().await.unwrap();
     }
 
     pub async fn set_blue(&mut self, value: u8, client: &mut Client) {
         self.blue_step = value;
         self.render(client);
-        client.renderall().await;
+        client.renderall().await.unwrap();
     }
 
     // render current state
     pub fn render(&self, client: &mut Client) {
-        for r in 0..20 {
-            let colour = Self::new(r, self.green_step, self.blue_step).to_rgb();
-            if r == self.red_step {
-                client.setcharcoloured(
-                    44,
-                    r as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
-                );
-                client.setcharcoloured(
-                    45,
-                    r as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
-                );
-            } else {
-                client.setcharcoloured(44, r as u32 + 1, ' ', Colour::Reset, colour.into());
-                client.setcharcoloured(45, r as u32 + 1, ' ', Colour::Reset, colour.into());
-            }
-        }
+        self.draw(&mut client.region(44, 1, 8, 20));
+    }
 
-        for g in 0..20 {
-            let colour = Self::new(self.red_step, g, self.blue_step).to_rgb();
-            if g == self.green_step {
-                client.setcharcoloured(
-                    47,
-                    g as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
-                );
-                client.setcharcoloured(
-                    48,
-                    g as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
-                );
-            } else {
-                client.setcharcoloured(47, g as u32 + 1, ' ', Colour::Reset, colour.into());
-                client.setcharcoloured(48, g as u32 + 1, ' ', Colour::Reset, colour.into());
-            }
-        }
+    // draw the red, green and blue bars next to each other inside a region
+    fn draw(&self, surface: &mut impl Surface) {
+        let bars = [
+            (0, self.red_step, Self::red as fn(&Self, u8) -> Self),
+            (3, self.green_step, Self::green),
+            (6, self.blue_step, Self::blue),
+        ];
 
-        for b in 0..20 {
-            let colour = Self::new(self.red_step, self.green_step, b).to_rgb();
-            if b == self.blue_step {
-                client.setcharcoloured(
-                    50,
-                    b as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
-                );
-                client.setcharcoloured(
-                    51,
-                    b as u32 + 1,
-                    ' ',
-                    Colour::Reset,
-                    colour.inverse().into(),
+        for (x, selected, with) in bars {
+            for step in 0..20 {
+                let colour = with(self, step).to_rgb();
+                let colour = if step == selected {
+                    colour.inverse()
+                } else {
+                    colour
+                };
+                surface.fill(
+                    x,
+                    step as u32,
+                    2,
+                    1,
+                    Cell::coloured(' ', Colour::Reset, colour.into()),
                 );
-            } else {
-                client.setcharcoloured(50, b as u32 + 1, ' ', Colour::Reset, colour.into());
-                client.setcharcoloured(51, b as u32 + 1, ' ', Colour::Reset, colour.into());
             }
         }
     }
 
+    // the same picker with a different value for one of the bars
+    fn red(&self, red: u8) -> Self {
+        Self::new(red, self.green_step, self.blue_step)
+    }
+
+    fn green(&self, green: u8) -> Self {
+        Self::new(self.red_step, green, self.blue_step)
+    }
+
+    fn blue(&self, blue: u8) -> Self {
+        Self::new(self.red_step, self.green_step, blue)
+    }
+
     // to standard 0 - 255 rgb
     pub fn to_rgb(&self) -> Rgb {
         Rgb {
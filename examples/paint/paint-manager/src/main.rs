@@ -1,14 +1,11 @@
 use libccanvas::{
-    bindings::{
-        Colour, Discriminator, EventVariant, KeyCode, ResponseContent, ResponseSuccess,
-        Subscription,
-    },
-    client::Client,
+    bindings::{Colour, Discriminator, EventVariant, KeyCode, Subscription},
+    client::{Client, ClientConfig, Surface},
 };
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
 
     client.hidecursor();
 
@@ -22,94 +19,85 @@ async fn main() {
             Subscription::specific_keycode(KeyCode::Char('5')),
             Subscription::specific_keycode(KeyCode::Char('q')),
         ])
-        .await;
+        .await
+        .unwrap();
+
+    // create all 5 canvas spaces at once, then wait for their responses
+    let spaces: Vec<_> = (0..5)
+        .map(|_| client.new_space(Discriminator::master(), "canvas".to_string()))
+        .collect();
 
     // this vector holds the 5 discriminators of the 5 spawned canvas spaces
     let mut canvas: Vec<Discriminator> = Vec::with_capacity(5);
-
-    for _ in 0..5 {
-        canvas.push(resp_to_discrim(
-            client
-                .new_space(Discriminator::master(), "canvas".to_string())
-                .await,
-        ));
+    for space in spaces {
+        canvas.push(space.await.unwrap());
     }
 
     // add 1 paint-canvas to each of those spaces
-    for subspace in canvas.iter() {
-        client
-            .spawn_at(
+    let painters: Vec<_> = canvas
+        .iter()
+        .map(|subspace| {
+            client.spawn_at(
                 "painter".to_string(),
                 "ccanvas-paint-canvas".to_string(),
                 Vec::new(),
                 subspace.clone(),
             )
-            .await;
+        })
+        .collect();
+    for painter in painters {
+        painter.await.unwrap();
     }
 
     // render some text to screen
-    for (x, c) in "Use mouse to draw on canvas. Ctrl + Z to undo."
-        .chars()
-        .enumerate()
-    {
-        client.setchar(x as u32, 21, c);
-    }
+    client.print(0, 21, "Use mouse to draw on canvas. Ctrl + Z to undo.");
 
     // set focus to canvas 1 by default
     let mut focused = 0;
-    client.focus_at(canvas[focused as usize].clone()).await;
+    client
+        .focus_at(canvas[focused as usize].clone())
+        .await
+        .unwrap();
 
     render_workspaces(focused, &mut client);
-    client.renderall().await;
+    client.renderall().await.unwrap();
 
     while let Some(event) = client.recv().await {
         if let EventVariant::Key(key) = event.get() {
             match key.code {
                 KeyCode::Char('q') => {
-                    client.exit().await;
+                    client.exit().await.unwrap();
                     break;
                 }
                 // change focus to the corresponding workspace if number keys are pressed
                 KeyCode::Char('1') => {
                     focused = 0;
-                    client.focus_at(canvas[0].clone()).await;
+                    client.focus_at(canvas[0].clone()).await.unwrap();
                 }
                 KeyCode::Char('2') => {
                     focused = 1;
-                    client.focus_at(canvas[1].clone()).await;
+                    client.focus_at(canvas[1].clone()).await.unwrap();
                 }
                 KeyCode::Char('3') => {
                     focused = 2;
-                    client.focus_at(canvas[2].clone()).await;
+                    client.focus_at(canvas[2].clone()).await.unwrap();
                 }
                 KeyCode::Char('4') => {
                     focused = 3;
-                    client.focus_at(canvas[3].clone()).await;
+                    client.focus_at(canvas[3].clone()).await.unwrap();
                 }
                 KeyCode::Char('5') => {
                     focused = 4;
-                    client.focus_at(canvas[4].clone()).await;
+                    client.focus_at(canvas[4].clone()).await.unwrap();
                 }
                 _ => continue,
             }
             render_workspaces(focused, &mut client);
-            client.renderall().await;
+            client.renderall().await.unwrap();
         }
     }
 }
 
-// returns the discriminator of the created space
-pub fn resp_to_discrim(res: ResponseContent) -> Discriminator {
-    if let ResponseContent::Success {
-        content: ResponseSuccess::SpaceCreated { discrim },
-    } = res
-    {
-        discrim
-    } else {
-        unreachable!("this is not a space spawned request")
-    }
-}
-
 // this function renders the workspace bar, highlighting the current selected workspace
 pub fn render_workspaces(focused: u32, client: &mut Client) {
     for x in 0..5 {
@@ -133,7 +121,5 @@ pub fn render_workspaces(focused: u32, client: &mut Client) {
     }
 
     // again render some text to screen
-    for (x, c) in "Press a key: 1 - 5 to goto canvas.".chars().enumerate() {
-        client.setchar(x as u32 + 12, 0, c);
-    }
+    client.print(12, 0, "Press a key: 1 - 5 to goto canvas.");
 }
@@ -2,7 +2,7 @@ use libccanvas::{bindings::*, client::*};
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
     client
         .subscribe_multiple(vec![
             Subscription::specific_keycode(KeyCode::Up),
@@ -11,29 +11,17 @@ async fn main() {
             Subscription::specific_keycode(KeyCode::Right),
             Subscription::specific_keycode(KeyCode::Char('q')),
         ])
-        .await;
+        .await
+        .unwrap();
     client.hidecursor();
 
     // draw the frame
-    for x in 1..41 {
-        client.setchar(x, 0, '─');
-        client.setchar(x, 21, '─');
-    }
-
-    for y in 1..21 {
-        client.setchar(0, y, '│');
-        client.setchar(41, y, '│');
-    }
-
-    client.setchar(41, 0, '╮');
-    client.setchar(0, 0, '╭');
-    client.setchar(41, 21, '╯');
-    client.setchar(0, 21, '╰');
+    client.rect(0, 0, 42, 22, BorderStyle::Rounded);
 
     // the changes are stashed
     // call renderall to flush all those changes to screen
     // reduced amount of renderall would decrease CPU usage
-    client.renderall().await;
+    client.renderall().await.unwrap();
 
     let mut x = 10;
     let mut y = 10;
@@ -42,7 +30,7 @@ async fn main() {
         if let EventVariant::Key(key) = event.get() {
             match key.code {
                 KeyCode::Char('q') => {
-                    client.exit().await;
+                    client.exit().await.unwrap();
                 }
                 KeyCode::Up if y > 1 => {
                     // characters have an almost 1:2 aspect ratio
@@ -67,7 +55,7 @@ async fn main() {
                 }
                 _ => {}
             }
-            client.renderall().await;
+            client.renderall().await.unwrap();
         }
     }
 }
@@ -1,11 +1,11 @@
 use libccanvas::{
     bindings::{EventVariant, KeyCode, Subscription},
-    client::Client,
+    client::{Client, ClientConfig},
 };
 
 #[tokio::main]
 async fn main() {
-    let client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
     // spawns in the 2 processes
     client
         .spawn(
@@ -13,24 +13,27 @@ async fn main() {
             "ccanvas-snake-main".to_string(),
             Vec::new(),
         )
-        .await;
+        .await
+        .unwrap();
     client
         .spawn(
             "snake-scoreboard".to_string(),
             "ccanvas-snake-scoreboard".to_string(),
             Vec::new(),
         )
-        .await;
+        .await
+        .unwrap();
 
     client
         .subscribe(Subscription::specific_keycode(KeyCode::Char('q')))
-        .await;
+        .await
+        .unwrap();
 
     // and listens for 'q' to exit the canvas
     while let Some(event) = client.recv().await {
         if let EventVariant::Key(key) = event.get() {
             if key.code == KeyCode::Char('q') {
-                client.exit().await;
+                client.exit().await.unwrap();
             }
         }
     }
@@ -3,13 +3,13 @@ use std::time::Duration;
 use ccanvas_snake_main::{Direction, Snake};
 use libccanvas::{
     bindings::{EventVariant, KeyCode, Subscription},
-    client::Client,
+    client::{BorderStyle, Client, ClientConfig, Surface},
 };
 use tokio::time::Instant;
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
     client
         .subscribe_multiple(vec![
             Subscription::specific_keycode(KeyCode::Up),
@@ -17,28 +17,16 @@ async fn main() {
             Subscription::specific_keycode(KeyCode::Left),
             Subscription::specific_keycode(KeyCode::Right),
         ])
-        .await;
+        .await
+        .unwrap();
     client.hidecursor();
 
     // draw the frame
-    for x in 1..43 {
-        client.setchar(x, 1, '─');
-        client.setchar(x, 23, '─');
-    }
-
-    for y in 2..24 {
-        client.setchar(0, y, '│');
-        client.setchar(43, y, '│');
-    }
-
-    client.setchar(43, 1, '╮');
-    client.setchar(0, 1, '╭');
-    client.setchar(43, 23, '╯');
-    client.setchar(0, 23, '╰');
+    client.rect(0, 1, 44, 23, BorderStyle::Rounded);
 
     let mut snake = Snake::new(&mut client).await;
 
-    client.renderall().await;
+    client.renderall().await.unwrap();
 
     // give it a bit of suspense
     let mut next_tick = Instant::now() + Duration::from_millis(500);
@@ -73,11 +61,11 @@ async fn main() {
         // this will freeze the screen and not exit yet
         if snake.game_over(&mut client).await {
             // flush all screen updates
-            client.renderall().await;
+            client.renderall().await.unwrap();
             break;
         }
 
-        client.renderall().await;
+        client.renderall().await.unwrap();
 
         // do some maths to make the snake go faster and faster
         // plot the graph in desmos to see how it looks like
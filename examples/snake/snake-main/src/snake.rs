@@ -36,7 +36,7 @@ impl Snake {
             *score += 1;
             // and broadcast the new score
             // so the scoreboard component will draw the new score
-            client.broadcast(score.to_string()).await;
+            client.broadcast(score.to_string()).await.unwrap();
         } else {
             // remove tail
             let (tailx, taily) = self.body.pop_back().unwrap();
@@ -103,7 +103,7 @@ impl Snake {
                 Colour::Reset,
             );
 
-            client.renderall().await;
+            client.renderall().await.unwrap();
             return true;
         }
 
@@ -1,31 +1,30 @@
 use libccanvas::{
-    bindings::{Colour, EventVariant, Subscription},
-    client::Client,
+    bindings::{Colour, EventVariant, Style, Subscription},
+    client::{Client, ClientConfig, Surface, TextStyle},
 };
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::default();
+    let mut client = Client::new(ClientConfig::default()).await.unwrap();
     // listen to all messages - including broadcasts from snake-main
-    client.subscribe(Subscription::AllMessages).await;
+    client.subscribe(Subscription::AllMessages).await.unwrap();
 
     // draws "Score: 0" in canvas
-    client.setchar(0, 0, 'S');
-    client.setchar(1, 0, 'c');
-    client.setchar(2, 0, 'o');
-    client.setchar(3, 0, 'r');
-    client.setchar(4, 0, 'e');
-    client.setchar(5, 0, ':');
+    client.print(0, 0, "Score:");
     client.setcharcoloured(7, 0, '0', Colour::Red, Colour::Reset);
-    client.renderall().await;
+    client.renderall().await.unwrap();
 
     while let Some(event) = client.recv().await {
         if let EventVariant::Message { content, .. } = event.get() {
-            for (x, c) in content.chars().enumerate() {
-                // draws the scroe in canvas
-                client.setcharcoloured(7 + x as u32, 0, c, Colour::LightRed, Colour::Reset);
-                client.renderall().await;
-            }
+            // draws the score in canvas
+            client.print_styled(
+                7,
+                0,
+                content,
+                &TextStyle::new()
+                    .with_style(Style::new().with_colours(Colour::LightRed, Colour::Reset)),
+            );
+            client.renderall().await.unwrap();
         }
     }
 }
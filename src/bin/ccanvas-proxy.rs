@@ -0,0 +1,83 @@
+//! logs all traffic between components and the canvas as json lines
+//!
+//! point components at the `--listen` socket instead of the canvas,
+//! for example with `--ccanvas-request-socket`
+
+use std::{fs::File, io, path::PathBuf, process::ExitCode};
+
+use libccanvas::proxy::{Proxy, ProxyConfig};
+
+const USAGE: &str = "usage: ccanvas-proxy --listen <socket> --upstream <socket> [--only <request type>]... [--log <file>]
+
+  --listen    socket for components to connect to
+  --upstream  request socket of the canvas
+  --only      only log requests of this type, such as \"render\" or \"set socket\"
+  --log       append to this file instead of writing to stdout";
+
+struct Args {
+    config: ProxyConfig,
+    log: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = match parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("ccanvas-proxy: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let proxy = match args.log {
+            Some(path) => {
+                let log = File::options().create(true).append(true).open(path)?;
+                Proxy::bind(args.config, log).await?
+            }
+            None => Proxy::bind(args.config, io::stdout()).await?,
+        };
+        proxy.run().await
+    })
+}
+
+fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut listen = None;
+    let mut upstream = None;
+    let mut only = Vec::new();
+    let mut log = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--listen" => listen = Some(value()?),
+            "--upstream" => upstream = Some(value()?),
+            "--only" => only.push(value()?),
+            "--log" => log = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown argument {arg}")),
+        }
+    }
+
+    let mut config = ProxyConfig::new(
+        listen.ok_or("--listen is required")?,
+        upstream.ok_or("--upstream is required")?,
+    );
+    if !only.is_empty() {
+        config = config.with_filter(only);
+    }
+
+    Ok(Args { config, log })
+}
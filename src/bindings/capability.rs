@@ -0,0 +1,45 @@
+use std::{borrow::Cow, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+/// version of the protocol spoken by these bindings,
+/// only changes when messages change in a way older versions cannot understand
+///
+/// additions which older versions can safely ignore are announced as capabilities instead
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// an optional feature of the protocol, exchanged when the listener is set
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Hash)]
+#[serde(transparent)]
+pub struct Capability(Cow<'static, str>);
+
+impl Capability {
+    /// events, errors and successes of unknown types are kept instead of failing to parse
+    pub const UNKNOWN_VARIANTS: Self = Self::from_static("unknown variants");
+
+    /// set styled char render requests, with text attributes such as bold
+    pub const STYLED_CHARS: Self = Self::from_static("styled chars");
+
+    pub const fn from_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(Cow::Owned(name.into()))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// all capabilities these bindings support
+    pub fn supported() -> Vec<Self> {
+        vec![Self::UNKNOWN_VARIANTS, Self::STYLED_CHARS]
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Attributes, Colour, RenderRequest, Style};
+
+/// a single character on the screen
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cell {
+    pub c: char,
+    pub fg: Colour,
+    pub bg: Colour,
+    #[serde(default)]
+    pub attributes: Attributes,
+}
+
+impl Cell {
+    /// a character without colours
+    pub fn new(c: char) -> Self {
+        Self::coloured(c, Colour::Reset, Colour::Reset)
+    }
+
+    pub fn coloured(c: char, fg: Colour, bg: Colour) -> Self {
+        Self::styled(c, Style::new().with_colours(fg, bg))
+    }
+
+    pub fn styled(c: char, style: Style) -> Self {
+        Self {
+            c,
+            fg: style.fg,
+            bg: style.bg,
+            attributes: style.attributes,
+        }
+    }
+
+    pub fn style(&self) -> Style {
+        Style {
+            fg: self.fg,
+            bg: self.bg,
+            attributes: self.attributes,
+        }
+    }
+
+    /// the request which draws this cell at the position, the simplest one which keeps its looks
+    pub fn to_request(&self, x: u32, y: u32) -> RenderRequest {
+        match (self.fg, self.bg, self.attributes) {
+            (_, _, attributes) if !attributes.is_empty() => {
+                RenderRequest::setchar_styled(x, y, self.c, self.style())
+            }
+            (Colour::Reset, Colour::Reset, _) => RenderRequest::setchar(x, y, self.c),
+            (fg, bg, _) => RenderRequest::setchar_coloured(x, y, self.c, fg, bg),
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
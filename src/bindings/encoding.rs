@@ -0,0 +1,63 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::FrameError;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+/// how messages are encoded inside of frames
+///
+/// json is always supported, other encodings are agreed with the server when the listener is set.
+/// which encodings exist depends on the enabled features, so matches need a wildcard arm
+#[non_exhaustive]
+pub enum Encoding {
+    #[default]
+    #[serde(rename = "json")]
+    Json,
+    /// compact binary encoding, requires the `msgpack` feature
+    #[cfg(feature = "msgpack")]
+    #[serde(rename = "msgpack")]
+    MessagePack,
+}
+
+impl Encoding {
+    /// all encodings this build supports, in order of preference
+    pub fn supported() -> Vec<Self> {
+        vec![
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack,
+            Self::Json,
+        ]
+    }
+
+    /// guess the encoding of a frame from its first byte
+    ///
+    /// every message is a json object or a msgpack map, which never start with the same byte
+    pub fn detect(payload: &[u8]) -> Self {
+        match payload.first() {
+            #[cfg(feature = "msgpack")]
+            Some(0x80..=0x8f | 0xde | 0xdf) => Self::MessagePack,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, FrameError> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| FrameError::Malformed(e.into())),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => {
+                rmp_serde::to_vec_named(value).map_err(|e| FrameError::Malformed(e.into()))
+            }
+        }
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, payload: &[u8]) -> Result<T, FrameError> {
+        match self {
+            Self::Json => {
+                serde_json::from_slice(payload).map_err(|e| FrameError::Malformed(e.into()))
+            }
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => {
+                rmp_serde::from_slice(payload).map_err(|e| FrameError::Malformed(e.into()))
+            }
+        }
+    }
+}
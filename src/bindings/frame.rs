@@ -0,0 +1,171 @@
+//! length delimited framing, so many messages can share one stream
+//!
+//! each frame is a 4 byte big endian length, followed by that many bytes of the encoded message,
+//! which is json unless another encoding has been agreed on
+
+use std::{error::Error, fmt::Display, io};
+
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::Encoding;
+
+/// frames longer than this are rejected unless a different limit is set
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// length of the frame header
+const HEADER_LEN: usize = 4;
+
+#[derive(Debug)]
+pub enum FrameError {
+    /// the stream ended in the middle of a frame
+    Partial { expected: usize, received: usize },
+    /// the frame is longer than the allowed limit
+    Oversized { len: usize, max: usize },
+    /// the frame content is not a valid message,
+    /// the stream is still intact and the next frame can be read
+    Malformed(Box<dyn Error + Send + Sync>),
+    /// reading from the stream failed
+    Io(io::Error),
+}
+
+impl Display for FrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Partial { expected, received } => write!(
+                f,
+                "stream ended in the middle of a frame, expected {expected} bytes but got {received}"
+            ),
+            Self::Oversized { len, max } => {
+                write!(f, "frame of {len} bytes exceeds the limit of {max} bytes")
+            }
+            Self::Malformed(e) => write!(f, "malformed frame: {e}"),
+            Self::Io(e) => write!(f, "failed to read frame: {e}"),
+        }
+    }
+}
+
+impl Error for FrameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed(e) => Some(e.as_ref()),
+            Self::Io(e) => Some(e),
+            Self::Partial { .. } | Self::Oversized { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for FrameError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// serialise a message into a single json frame, including its header
+pub fn encode_frame<T: Serialize>(value: &T) -> Result<Vec<u8>, FrameError> {
+    encode_frame_with(value, Encoding::Json)
+}
+
+/// serialise a message into a single frame with the given encoding, including its header
+pub fn encode_frame_with<T: Serialize>(
+    value: &T,
+    encoding: Encoding,
+) -> Result<Vec<u8>, FrameError> {
+    let mut frame = vec![0; HEADER_LEN];
+    frame.append(&mut encoding.encode(value)?);
+
+    let len = frame.len() - HEADER_LEN;
+    if len > MAX_FRAME_LEN {
+        return Err(FrameError::Oversized {
+            len,
+            max: MAX_FRAME_LEN,
+        });
+    }
+
+    frame[..HEADER_LEN].copy_from_slice(&(len as u32).to_be_bytes());
+    Ok(frame)
+}
+
+/// reads frames one by one from a stream
+pub struct FrameReader<R> {
+    inner: R,
+    max_len: usize,
+}
+
+impl<R: AsyncRead + Unpin> FrameReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            max_len: MAX_FRAME_LEN,
+        }
+    }
+
+    /// reject frames longer than `max_len`
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// read and deserialise the next frame, in whichever encoding it was sent with
+    ///
+    /// returns none if the stream ended cleanly between two frames
+    pub async fn next<T: DeserializeOwned>(&mut self) -> Result<Option<T>, FrameError> {
+        match self.next_payload().await? {
+            Some(payload) => Encoding::detect(&payload).decode(&payload).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// read the next frame without deserialising it, returns the payload without its header
+    ///
+    /// returns none if the stream ended cleanly between two frames
+    pub async fn next_payload(&mut self) -> Result<Option<Vec<u8>>, FrameError> {
+        let mut header = [0; HEADER_LEN];
+        let received = self.read_full(&mut header).await?;
+        if received == 0 {
+            return Ok(None);
+        }
+        if received < HEADER_LEN {
+            return Err(FrameError::Partial {
+                expected: HEADER_LEN,
+                received,
+            });
+        }
+
+        let len = u32::from_be_bytes(header) as usize;
+        if len > self.max_len {
+            return Err(FrameError::Oversized {
+                len,
+                max: self.max_len,
+            });
+        }
+
+        let mut payload = vec![0; len];
+        let received = self.read_full(&mut payload).await?;
+        if received < len {
+            return Err(FrameError::Partial {
+                expected: len,
+                received,
+            });
+        }
+
+        Ok(Some(payload))
+    }
+
+    /// consumes the reader, returning the underlying stream
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// fill the buffer, stopping early only if the stream ends
+    async fn read_full(&mut self, buf: &mut [u8]) -> Result<usize, FrameError> {
+        let mut received = 0;
+        while received < buf.len() {
+            match self.inner.read(&mut buf[received..]).await? {
+                0 => break,
+                n => received += n,
+            }
+        }
+        Ok(received)
+    }
+}
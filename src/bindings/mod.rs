@@ -6,3 +6,15 @@ pub use request::*;
 
 mod response;
 pub use response::*;
+
+mod cell;
+pub use cell::*;
+
+mod frame;
+pub use frame::*;
+
+mod encoding;
+pub use encoding::*;
+
+mod capability;
+pub use capability::*;
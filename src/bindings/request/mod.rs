@@ -3,3 +3,9 @@ pub use subscription::*;
 
 mod request;
 pub use request::*;
+
+mod requestids;
+pub use requestids::*;
+
+mod style;
+pub use style::*;
@@ -1,12 +1,12 @@
 use std::path::PathBuf;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{bindings::Discriminator, client::Client};
+use crate::bindings::{Capability, Discriminator, Encoding};
 
-use super::Subscription;
+use super::{Attributes, RequestIds, Style, Subscription};
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 /// request to send to the server
 pub struct Request {
     /// reciever
@@ -18,12 +18,12 @@ pub struct Request {
 }
 
 impl Request {
-    /// construct new self with a unique id
-    pub fn new(target: Discriminator, content: RequestContent) -> Self {
+    /// construct new self with a unique id from the allocator
+    pub fn new(target: Discriminator, content: RequestContent, ids: &RequestIds) -> Self {
         Self {
             target,
             content,
-            id: Client::reqid(),
+            id: ids.next(),
         }
     }
 
@@ -31,9 +31,14 @@ impl Request {
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    /// returns the component the request is sent to
+    pub fn target(&self) -> &Discriminator {
+        &self.target
+    }
 }
 
-#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(tag = "type")]
 /// the real content of the request sent to server
 pub enum RequestContent {
@@ -54,7 +59,8 @@ pub enum RequestContent {
         component: Option<Discriminator>,
     },
 
-    #[serde(rename = "Unsubscribe")]
+    // the server expects the capitalised tag, the lowercase one is accepted for consistency
+    #[serde(rename = "Unsubscribe", alias = "unsubscribe")]
     /// remove subscription from a channel
     Unsubscribe {
         channel: Subscription,
@@ -63,7 +69,18 @@ pub enum RequestContent {
 
     #[serde(rename = "set socket")]
     /// sent responses to this socket
-    SetSocket { path: PathBuf },
+    SetSocket {
+        path: PathBuf,
+        /// encodings the client can use, in order of preference
+        #[serde(default)]
+        encodings: Vec<Encoding>,
+        /// protocol version of the client, 0 for clients from before versioning
+        #[serde(default)]
+        protocol: u32,
+        /// optional features the client supports
+        #[serde(default)]
+        capabilities: Vec<Capability>,
+    },
 
     #[serde(rename = "drop")]
     /// remove a single component
@@ -100,7 +117,25 @@ pub enum RequestContent {
     FocusAt,
 }
 
-#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+impl RequestContent {
+    /// the type of the request as it is tagged on the wire, such as `"set socket"`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ConfirmRecieve { .. } => "confirm recieve",
+            Self::Subscribe { .. } => "subscribe",
+            Self::Unsubscribe { .. } => "Unsubscribe",
+            Self::SetSocket { .. } => "set socket",
+            Self::Drop { .. } => "drop",
+            Self::Render { .. } => "render",
+            Self::Spawn { .. } => "spawn",
+            Self::Message { .. } => "message",
+            Self::NewSpace { .. } => "new space",
+            Self::FocusAt => "focus at",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(tag = "type")]
 /// a render request to the server
 pub enum RenderRequest {
@@ -116,6 +151,16 @@ pub enum RenderRequest {
         fg: Colour,
         bg: Colour,
     },
+    /// change a single character, with colours and attributes
+    ///
+    /// only sent to servers with the styled chars capability
+    #[serde(rename = "set styledchar")]
+    SetCharStyled {
+        x: u32,
+        y: u32,
+        c: char,
+        style: Style,
+    },
     /// all the terminal to flush all changes, this is usually not needed
     #[serde(rename = "flush")]
     Flush,
@@ -146,13 +191,38 @@ impl RenderRequest {
         Self::SetCharColoured { x, y, c, fg, bg }
     }
 
+    /// create a setchar styled request
+    pub fn setchar_styled(x: u32, y: u32, c: char, style: Style) -> Self {
+        Self::SetCharStyled { x, y, c, style }
+    }
+
+    /// the same request without attributes, for servers which do not support them
+    ///
+    /// styled characters become coloured characters, the colours of reversed ones are swapped
+    pub fn colour_only(self) -> Self {
+        match self {
+            Self::SetCharStyled { x, y, c, style } => {
+                let (fg, bg) = if style.attributes.contains(Attributes::REVERSE) {
+                    (style.bg, style.fg)
+                } else {
+                    (style.fg, style.bg)
+                };
+                Self::SetCharColoured { x, y, c, fg, bg }
+            }
+            Self::RenderMultiple { tasks } => Self::RenderMultiple {
+                tasks: tasks.into_iter().map(Self::colour_only).collect(),
+            },
+            other => other,
+        }
+    }
+
     /// create a setcursor request
     pub fn setcursor(style: CursorStyle) -> Self {
         Self::SetCursorStyle { style }
     }
 }
 
-#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 /// how the cursor should look
 pub enum CursorStyle {
     #[serde(rename = "blinking bar")]
@@ -169,7 +239,7 @@ pub enum CursorStyle {
     SteadyUnderline,
 }
 
-#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[serde(tag = "type")]
 /// generic colours
 pub enum Colour {
@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// hands out request ids, unique among all requests created from the same allocator
+///
+/// safe to share between threads, every client has its own
+#[derive(Default, Debug)]
+pub struct RequestIds(AtomicU32);
+
+impl RequestIds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// continue counting from an id which has already been used
+    pub fn starting_after(id: u32) -> Self {
+        Self(AtomicU32::new(id))
+    }
+
+    /// get a unique request id, 0 is never used
+    ///
+    /// after u32::MAX ids the count starts over from 1,
+    /// by then the earlier requests are long done
+    pub fn next(&self) -> u32 {
+        let increment = |id: u32| id.checked_add(1).unwrap_or(1);
+        let previous = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| {
+                Some(increment(id))
+            })
+            .unwrap_or_else(|id| id);
+        increment(previous)
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use serde::{Deserialize, Serialize};
+
+use super::Colour;
+
+/// colours and attributes of a character
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Style {
+    pub fg: Colour,
+    pub bg: Colour,
+    #[serde(default)]
+    pub attributes: Attributes,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fg: Colour::Reset,
+            bg: Colour::Reset,
+            attributes: Attributes::NONE,
+        }
+    }
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_colours(mut self, fg: Colour, bg: Colour) -> Self {
+        self.fg = fg;
+        self.bg = bg;
+        self
+    }
+
+    /// replaces the attributes, combine them with `|`
+    pub fn with_attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+}
+
+/// text attributes such as bold and underline, as a set of bits
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[serde(transparent)]
+pub struct Attributes(u16);
+
+impl Attributes {
+    pub const NONE: Self = Self(0);
+    pub const BOLD: Self = Self(1 << 0);
+    pub const DIM: Self = Self(1 << 1);
+    pub const ITALIC: Self = Self(1 << 2);
+    pub const UNDERLINE: Self = Self(1 << 3);
+    /// swaps the foreground and background colours
+    pub const REVERSE: Self = Self(1 << 4);
+    pub const STRIKETHROUGH: Self = Self(1 << 5);
+
+    /// bits which are not known to these bindings are kept as they are
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// whether all attributes in `other` are set
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
@@ -1,8 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::bindings::{Discriminator, KeyCode, KeyEvent, KeyModifier, MouseType};
 
-#[derive(Hash, PartialEq, Eq, Serialize, Debug, Clone)]
+#[derive(Hash, PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 /// a single subscription request
 pub enum Subscription {
@@ -69,8 +69,8 @@ impl Subscription {
     }
 }
 
-impl Into<(Self, Option<u32>)> for Subscription {
-    fn into(self) -> (Self, Option<u32>) {
-        (self, None)
+impl From<Subscription> for (Subscription, Option<u32>) {
+    fn from(value: Subscription) -> Self {
+        (value, None)
     }
 }
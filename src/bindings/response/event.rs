@@ -1,6 +1,11 @@
+use std::sync::Arc;
+
 use tokio::sync::mpsc::UnboundedSender;
 
-use crate::bindings::{Discriminator, Request, RequestContent};
+use crate::{
+    bindings::{Discriminator, Request, RequestContent, RequestIds},
+    client::ClientError,
+};
 
 use super::EventVariant;
 
@@ -9,28 +14,38 @@ pub struct Event {
     /// real content of the event
     content: EventVariant,
     /// confirmation handle to release the event
-    confirm: Option<(u32, UnboundedSender<Request>)>,
+    confirm: Option<(u32, UnboundedSender<Request>, Arc<RequestIds>)>,
 }
 
 impl Event {
-    pub fn new(content: EventVariant, sender: UnboundedSender<Request>, confirm: u32) -> Self {
+    pub fn new(
+        content: EventVariant,
+        sender: UnboundedSender<Request>,
+        ids: Arc<RequestIds>,
+        confirm: u32,
+    ) -> Self {
         Self {
             content,
-            confirm: Some((confirm, sender)),
+            confirm: Some((confirm, sender, ids)),
         }
     }
 
     /// mark the event as done and release the event
     /// pass = false will capture the event and no lower components can recieve it
-    pub fn done(&mut self, pass: bool) {
-        if let Some((id, sender)) = std::mem::take(&mut self.confirm) {
+    ///
+    /// errors if the client is no longer connected, the event is released anyways
+    pub fn done(&mut self, pass: bool) -> Result<(), ClientError> {
+        if let Some((id, sender, ids)) = std::mem::take(&mut self.confirm) {
             sender
                 .send(Request::new(
                     Discriminator::default(),
                     RequestContent::ConfirmRecieve { id, pass },
+                    &ids,
                 ))
-                .unwrap();
+                .map_err(|_| ClientError::Disconnected)?;
         }
+
+        Ok(())
     }
 
     /// returns a reference to the content
@@ -46,6 +61,6 @@ impl Event {
 
 impl Drop for Event {
     fn drop(&mut self) {
-        self.done(true)
+        let _ = self.done(true);
     }
 }
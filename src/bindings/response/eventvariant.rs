@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::bindings::Discriminator;
 
-#[derive(Deserialize, Clone, PartialEq, Debug, Hash)]
+use super::RawJson;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Hash)]
 #[serde(tag = "type")]
 pub enum EventVariant {
     /// keyboard event
@@ -26,6 +28,13 @@ pub enum EventVariant {
     Focused,
     #[serde(rename = "unfocused")]
     Unfocused,
+    /// an event this version does not know about, with its raw content including the type
+    ///
+    /// newer canvas versions may send events that are not listed here,
+    /// they are kept so the event can still be confirmed.
+    /// a known type whose fields do not match, such as a resize without a width, is also unknown
+    #[serde(untagged)]
+    Unknown(RawJson),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Hash)]
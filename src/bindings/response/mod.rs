@@ -6,3 +6,6 @@ pub use eventvariant::*;
 
 mod event;
 pub use event::*;
+
+mod rawjson;
+pub use rawjson::*;
@@ -0,0 +1,34 @@
+use std::{
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// the raw content of a message this version does not understand, including its type
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(transparent)]
+pub struct RawJson(pub Value);
+
+impl RawJson {
+    /// the type tag of the message, if it has one
+    pub fn kind(&self) -> Option<&str> {
+        self.0.get("type").and_then(Value::as_str)
+    }
+}
+
+impl Deref for RawJson {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Hash for RawJson {
+    /// object keys are sorted, so equal values always serialise the same way
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_string().hash(state)
+    }
+}
@@ -1,10 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::bindings::Discriminator;
+use crate::bindings::{Capability, Discriminator, Encoding};
 
-use super::EventVariant;
+use super::{EventVariant, RawJson};
 
-#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Response {
     /// the content of the response
     pub content: ResponseContent,
@@ -18,7 +18,7 @@ pub struct Response {
     pub request: Option<u32>,
 }
 
-#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(tag = "type")]
 pub enum ResponseContent {
     #[serde(rename = "undelivered")]
@@ -34,16 +34,21 @@ pub enum ResponseContent {
     Success { content: ResponseSuccess },
 }
 
-#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(tag = "type")]
 pub enum ResponseError {
     #[serde(rename = "component not found")]
     ComponentNotFound,
     #[serde(rename = "spawn failed")]
     SpawnFailed,
+    /// an error this version does not know about, with its raw content including the type
+    ///
+    /// a known type whose fields do not match is also unknown
+    #[serde(untagged)]
+    Unknown(RawJson),
 }
 
-#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(tag = "type")]
 pub enum ResponseSuccess {
     #[serde(rename = "subscribe added")]
@@ -53,7 +58,19 @@ pub enum ResponseSuccess {
     SubscribeRemoved,
 
     #[serde(rename = "listener set")]
-    ListenerSet,
+    ListenerSet {
+        /// encoding for all following messages, servers without encoding support only use json
+        #[serde(default)]
+        encoding: Encoding,
+        /// protocol version of the server
+        ///
+        /// servers from before versioning do not send one, they already read frames so they are still compatible
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        protocol: Option<u32>,
+        /// optional features the server supports
+        #[serde(default)]
+        capabilities: Vec<Capability>,
+    },
 
     #[serde(rename = "dropped")]
     Dropped,
@@ -72,4 +89,9 @@ pub enum ResponseSuccess {
 
     #[serde(rename = "focus changed")]
     FocusChanged,
+    /// a success this version does not know about, with its raw content including the type
+    ///
+    /// a known type whose fields do not match is also unknown
+    #[serde(untagged)]
+    Unknown(RawJson),
 }
@@ -0,0 +1,221 @@
+/// which characters lines and borders are drawn with
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BorderStyle {
+    /// `┌─┐`
+    #[default]
+    Single,
+    /// `╔═╗`
+    Double,
+    /// `╭─╮`, lines are the same as single
+    Rounded,
+    /// `┏━┓`
+    Heavy,
+    /// `+-+`, for terminals without box drawing characters
+    Ascii,
+}
+
+/// how thick a single arm of a box drawing character is
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Weight {
+    Light,
+    Heavy,
+    Double,
+    Ascii,
+}
+
+/// the arms of a box drawing character, clockwise from up
+pub(crate) type Arms = [Option<Weight>; 4];
+
+/// box drawing characters by their arms, clockwise from up,
+/// `.` for none, `L` for light, `H` for heavy and `D` for double
+///
+/// rounded corners come after the square ones, so square corners are picked unless asked for otherwise
+const CHARACTERS: &[(char, &str)] = &[
+    ('─', ".L.L"),
+    ('━', ".H.H"),
+    ('│', "L.L."),
+    ('┃', "H.H."),
+    ('═', ".D.D"),
+    ('║', "D.D."),
+    ('┌', ".LL."),
+    ('┍', ".HL."),
+    ('┎', ".LH."),
+    ('┏', ".HH."),
+    ('┐', "..LL"),
+    ('┑', "..LH"),
+    ('┒', "..HL"),
+    ('┓', "..HH"),
+    ('└', "LL.."),
+    ('┕', "LH.."),
+    ('┖', "HL.."),
+    ('┗', "HH.."),
+    ('┘', "L..L"),
+    ('┙', "L..H"),
+    ('┚', "H..L"),
+    ('┛', "H..H"),
+    ('├', "LLL."),
+    ('┝', "LHL."),
+    ('┞', "HLL."),
+    ('┟', "LLH."),
+    ('┠', "HLH."),
+    ('┡', "HHL."),
+    ('┢', "LHH."),
+    ('┣', "HHH."),
+    ('┤', "L.LL"),
+    ('┥', "L.LH"),
+    ('┦', "H.LL"),
+    ('┧', "L.HL"),
+    ('┨', "H.HL"),
+    ('┩', "H.LH"),
+    ('┪', "L.HH"),
+    ('┫', "H.HH"),
+    ('┬', ".LLL"),
+    ('┭', ".LLH"),
+    ('┮', ".HLL"),
+    ('┯', ".HLH"),
+    ('┰', ".LHL"),
+    ('┱', ".LHH"),
+    ('┲', ".HHL"),
+    ('┳', ".HHH"),
+    ('┴', "LL.L"),
+    ('┵', "LL.H"),
+    ('┶', "LH.L"),
+    ('┷', "LH.H"),
+    ('┸', "HL.L"),
+    ('┹', "HL.H"),
+    ('┺', "HH.L"),
+    ('┻', "HH.H"),
+    ('┼', "LLLL"),
+    ('┽', "LLLH"),
+    ('┾', "LHLL"),
+    ('┿', "LHLH"),
+    ('╀', "HLLL"),
+    ('╁', "LLHL"),
+    ('╂', "HLHL"),
+    ('╃', "HLLH"),
+    ('╄', "HHLL"),
+    ('╅', "LLHH"),
+    ('╆', "LHHL"),
+    ('╇', "HHLH"),
+    ('╈', "LHHH"),
+    ('╉', "HLHH"),
+    ('╊', "HHHL"),
+    ('╋', "HHHH"),
+    ('╒', ".DL."),
+    ('╓', ".LD."),
+    ('╔', ".DD."),
+    ('╕', "..LD"),
+    ('╖', "..DL"),
+    ('╗', "..DD"),
+    ('╘', "LD.."),
+    ('╙', "DL.."),
+    ('╚', "DD.."),
+    ('╛', "L..D"),
+    ('╜', "D..L"),
+    ('╝', "D..D"),
+    ('╞', "LDL."),
+    ('╟', "DLD."),
+    ('╠', "DDD."),
+    ('╡', "L.LD"),
+    ('╢', "D.DL"),
+    ('╣', "D.DD"),
+    ('╤', ".DLD"),
+    ('╥', ".LDL"),
+    ('╦', ".DDD"),
+    ('╧', "LD.D"),
+    ('╨', "DL.L"),
+    ('╩', "DD.D"),
+    ('╪', "LDLD"),
+    ('╫', "DLDL"),
+    ('╬', "DDDD"),
+    ('╭', ".LL."),
+    ('╮', "..LL"),
+    ('╯', "L..L"),
+    ('╰', "LL.."),
+];
+
+impl BorderStyle {
+    pub(crate) fn weight(&self) -> Weight {
+        match self {
+            Self::Single | Self::Rounded => Weight::Light,
+            Self::Heavy => Weight::Heavy,
+            Self::Double => Weight::Double,
+            Self::Ascii => Weight::Ascii,
+        }
+    }
+
+    /// a character with the arms, joining with the box drawing character `existing` if there is one
+    ///
+    /// mixes without a character of their own, such as heavy and double,
+    /// are drawn entirely in this style
+    pub(crate) fn junction(&self, arms: Arms, existing: Option<char>) -> char {
+        let mut arms = match existing.and_then(arms_of) {
+            Some(existing) => [0, 1, 2, 3].map(|i| arms[i].or(existing[i])),
+            None => arms,
+        };
+
+        // the end of a line which does not join anything is drawn straight
+        if let [(i, Some(weight))] = arms
+            .iter()
+            .enumerate()
+            .filter(|(_, arm)| arm.is_some())
+            .collect::<Vec<_>>()[..]
+        {
+            arms[(i + 2) % 4] = Some(*weight);
+        }
+
+        self.character(arms).unwrap_or_else(|| {
+            let weight = self.weight();
+            self.character(arms.map(|arm| arm.map(|_| weight)))
+                .unwrap_or(' ')
+        })
+    }
+
+    fn character(&self, arms: Arms) -> Option<char> {
+        if arms.iter().flatten().all(|arm| *arm == Weight::Ascii) {
+            return match arms {
+                [None, None, None, None] => None,
+                [None, _, None, _] => Some('-'),
+                [_, None, _, None] => Some('|'),
+                _ => Some('+'),
+            };
+        }
+
+        let mut found = CHARACTERS
+            .iter()
+            .filter(|(_, pattern)| parse(pattern) == arms)
+            .map(|(c, _)| *c);
+        match self {
+            // corners have a rounded version, junctions do not
+            Self::Rounded => found.next_back(),
+            _ => found.next(),
+        }
+    }
+}
+
+/// the arms of a box drawing character, none if it is not one
+fn arms_of(c: char) -> Option<Arms> {
+    let w = Some(Weight::Ascii);
+    match c {
+        '-' => Some([None, w, None, w]),
+        '|' => Some([w, None, w, None]),
+        '+' => Some([w, w, w, w]),
+        _ => CHARACTERS
+            .iter()
+            .find(|(found, _)| *found == c)
+            .map(|(_, pattern)| parse(pattern)),
+    }
+}
+
+fn parse(pattern: &str) -> Arms {
+    let mut arms = [None; 4];
+    for (arm, c) in arms.iter_mut().zip(pattern.chars()) {
+        *arm = match c {
+            'L' => Some(Weight::Light),
+            'H' => Some(Weight::Heavy),
+            'D' => Some(Weight::Double),
+            _ => None,
+        };
+    }
+    arms
+}
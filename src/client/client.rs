@@ -1,236 +1,399 @@
 use std::{
-    collections::HashMap,
-    io::{Read, Write},
-    os::unix::net::{UnixListener, UnixStream},
-    path::PathBuf,
-    sync::Arc,
+    io::ErrorKind,
+    os::unix::{fs::FileTypeExt, net::UnixStream},
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+    time::Duration,
 };
 
 use tokio::{
-    sync::{
-        mpsc::{self, UnboundedReceiver, UnboundedSender},
-        oneshot, Mutex, OnceCell,
-    },
+    io::AsyncWriteExt,
+    net::UnixListener,
+    sync::mpsc::{self, UnboundedSender},
     task::JoinHandle,
 };
 
-use crate::bindings::{
-    Colour, CursorStyle, Discriminator, Event, RenderRequest, Request, RequestContent, Response,
-    ResponseContent, ResponseSuccess, Subscription,
+use crate::{
+    bindings::{
+        encode_frame_with, Capability, Cell, Colour, CursorStyle, Discriminator, Encoding, Event,
+        FrameError, FrameReader, RenderRequest, Request, RequestContent, RequestIds, Response,
+        ResponseContent, ResponseSuccess, Subscription, PROTOCOL_VERSION,
+    },
+    util::AbortOnDrop,
 };
 
-use super::ClientConfig;
+use super::{
+    ClientConfig, ClientError, ClientHandle, EventRouter, EventStream, FrameBuffer,
+    PendingResponse, ReqConfirms, Surface,
+};
 
+/// all request functions of `ClientHandle` can be called on the client directly
 pub struct Client {
     /// task handle to the listener loop
     listener_handle: JoinHandle<()>,
     /// task handle for sender loop
     request_handle: JoinHandle<()>,
     /// incoming events
-    inbound_recv: Arc<Mutex<UnboundedReceiver<Event>>>,
-    /// request to ccanvas
-    outbound_send: UnboundedSender<Request>,
-
-    /// path to request socket
-    request_socket: PathBuf,
-    /// unflushed render requests
+    events: EventStream,
+    /// sends requests to ccanvas
+    handle: ClientHandle,
+
+    /// path to the listener socket, removed when the client is closed or dropped
+    listener_socket: PathBuf,
+    /// cells drawn so far, only the changed ones are sent on renderall
+    frame: FrameBuffer,
+    /// unflushed render requests which are not cells
     render_requests: Vec<RenderRequest>,
-    /// confirmation handles for requests
-    req_confirms: Arc<Mutex<HashMap<u32, oneshot::Sender<ResponseContent>>>>,
+    /// encoding of requests, agreed with the server when the listener is set
+    encoding: Arc<OnceLock<Encoding>>,
+    /// set once `close()` has run, so drop has nothing left to do
+    closed: bool,
+    /// protocol version of the server, none if it is from before versioning
+    protocol: Option<u32>,
+    /// optional features the server supports
+    capabilities: Vec<Capability>,
 }
 
-impl Default for Client {
-    fn default() -> Self {
-        Self::new(ClientConfig::default())
-    }
-}
-
-static mut REQID: OnceCell<u32> = OnceCell::const_new_with(0);
-
 impl Client {
-    pub fn new(config: ClientConfig) -> Self {
-        // creates the listener socket
-        let listener = UnixListener::bind(&config.listener_socket).unwrap();
+    /// connects to the canvas, returns once the canvas has acknowledged the listener socket
+    pub async fn new(config: ClientConfig) -> Result<Self, ClientError> {
+        let events = EventStream::new();
+        let (outbound_send, mut outbound_recv) = mpsc::unbounded_channel::<Request>();
+        let req_confirms = Arc::new(ReqConfirms::default());
+        let encoding = Arc::new(OnceLock::new());
+        let ids = Arc::new(RequestIds::new());
+
+        // all requests share this one connection
+        let connection = tokio::net::UnixStream::connect(&config.request_socket)
+            .await
+            .map_err(|source| ClientError::Connect {
+                path: config.request_socket.clone(),
+                source,
+            })?;
 
-        let (inbound_send, inbound_recv) = mpsc::unbounded_channel();
-        let (outbound_send, mut outbound_recv) = mpsc::unbounded_channel();
-        let req_confirms: Arc<Mutex<HashMap<u32, oneshot::Sender<ResponseContent>>>> =
-            Arc::new(Mutex::new(HashMap::default()));
-
-        // connects and set the listener
-        let set_socket = Request::new(
-            Discriminator::default(),
-            RequestContent::SetSocket {
-                path: config.listener_socket,
-            },
-        );
-        UnixStream::connect(&config.request_socket)
-            .unwrap()
-            .write_all(serde_json::to_vec(&set_socket).unwrap().as_slice())
-            .unwrap();
+        // creates the listener socket
+        let listener = Self::bind_listener(&config.listener_socket)?;
 
         let listener_handle = {
             let outbound_send = outbound_send.clone();
             let req_confirms = req_confirms.clone();
-            tokio::task::spawn_blocking(move || {
-                let outbound_send = outbound_send.clone();
-                for stream in listener.incoming() {
-                    let mut stream = match stream {
-                        Ok(stream) => stream,
-                        Err(_) => continue,
-                    };
-
-                    let mut msg = String::new();
-                    match stream.read_to_string(&mut msg) {
-                        Ok(_) => {}
-                        Err(_) => continue,
-                    }
-
-                    let res: Response = match serde_json::from_str(&msg) {
-                        Ok(res) => res,
+            let ids = ids.clone();
+            let router = events.router();
+            // aborting the accept loop drops every connection task with it
+            tokio::task::spawn(async move {
+                let mut connections = Vec::new();
+                loop {
+                    let stream = match listener.accept().await {
+                        Ok((stream, _)) => stream,
                         Err(_) => continue,
                     };
 
-                    match res.content {
-                        // events have to be confirmed
-                        ResponseContent::Event { content } => {
-                            inbound_send
-                                .send(Event::new(content, outbound_send.clone(), res.id))
-                                .unwrap();
-                        }
-                        // these are responses from canvas
-                        // and dont have to be confirmed
-                        // but their wait locks have to be released
-                        // so the callers can know the task is done
-                        ResponseContent::Error { .. }
-                        | ResponseContent::Success { .. }
-                        | ResponseContent::Undelivered => {
-                            if let Some(entry) = tokio::runtime::Runtime::new()
-                                .unwrap()
-                                .block_on(req_confirms.lock())
-                                .remove(&res.request.unwrap())
-                            {
-                                entry.send(res.content).unwrap();
-                            }
-                        }
-                    }
+                    connections.retain(|task: &AbortOnDrop| !task.0.is_finished());
+                    // each connection may carry any number of responses
+                    connections.push(AbortOnDrop(tokio::task::spawn(Self::listen(
+                        FrameReader::new(stream),
+                        router.clone(),
+                        outbound_send.clone(),
+                        req_confirms.clone(),
+                        ids.clone(),
+                    ))));
                 }
             })
         };
 
+        let set_socket = RequestContent::SetSocket {
+            path: config.listener_socket.clone(),
+            encodings: Encoding::supported(),
+            protocol: PROTOCOL_VERSION,
+            capabilities: Capability::supported(),
+        };
+
         let request_handle = {
             let request_socket = config.request_socket.clone();
-            // simply sends Request to canvas
+            let req_confirms = req_confirms.clone();
+            let encoding = encoding.clone();
+            let ids = ids.clone();
+            let set_socket = set_socket.clone();
+            // sends Request to canvas one at a time
+            // so they arrive in the same order as they are sent
             tokio::task::spawn(async move {
+                let mut connection = Some(connection);
                 while let Some(req) = outbound_recv.recv().await {
-                    let request_socket = request_socket.clone();
-                    tokio::task::spawn_blocking(move || {
-                        UnixStream::connect(request_socket)
-                            .unwrap()
-                            .write_all(serde_json::to_vec(&req).unwrap().as_slice())
-                            .unwrap();
-                    });
+                    let encoding = encoding.get().copied().unwrap_or_default();
+                    if let Err(e) = Self::write_persistent(
+                        &mut connection,
+                        &request_socket,
+                        &req,
+                        encoding,
+                        &set_socket,
+                        &ids,
+                    )
+                    .await
+                    {
+                        // release the caller with the error instead of leaving it waiting
+                        req_confirms.resolve(req.id(), Err(e));
+                    }
+
+                    // nothing can be sent after the client is dropped from the canvas
+                    if matches!(req.content, RequestContent::Drop { discrim: None }) {
+                        break;
+                    }
                 }
             })
         };
 
-        Self {
+        let mut client = Self {
             listener_handle,
             request_handle,
-            inbound_recv: Arc::new(Mutex::new(inbound_recv)),
-            outbound_send,
-            request_socket: config.request_socket,
+            events,
+            handle: ClientHandle::new(outbound_send, req_confirms, ids, config.request_timeout),
+            listener_socket: config.listener_socket.clone(),
+            frame: FrameBuffer::new(),
             render_requests: Vec::new(),
-            req_confirms,
+            encoding,
+            closed: false,
+            protocol: None,
+            capabilities: Vec::new(),
+        };
+
+        // set the listener, the set socket request itself is always sent as json
+        let set_socket = client.handle.request(Discriminator::default(), set_socket);
+        match client.handle.send(set_socket).await? {
+            ResponseContent::Success {
+                content:
+                    ResponseSuccess::ListenerSet {
+                        encoding,
+                        protocol,
+                        capabilities,
+                    },
+            } => {
+                if let Some(server) = protocol.filter(|server| *server != PROTOCOL_VERSION) {
+                    return Err(ClientError::Incompatible {
+                        client: PROTOCOL_VERSION,
+                        server,
+                    });
+                }
+                let _ = client.encoding.set(encoding);
+                client.protocol = protocol;
+                client.capabilities = capabilities;
+            }
+            ResponseContent::Error { content } => return Err(ClientError::Response(content)),
+            res => return Err(ClientError::UnexpectedResponse(res)),
         }
+
+        Ok(client)
     }
 
-    /// get a unique request id
-    pub fn reqid() -> u32 {
-        let id = unsafe { REQID.get_mut() }.unwrap();
-        *id += 1;
-        *id
+    /// a handle that can send requests from any task,
+    /// while this client keeps receiving events
+    pub fn handle(&self) -> ClientHandle {
+        self.handle.clone()
     }
 
-    /// there should only be one recv() per program
-    /// more than one recv() at a time results in almost randomised behaviour
-    pub async fn recv(&self) -> Option<Event> {
-        self.inbound_recv.lock().await.recv().await
+    /// protocol version of the server, none if the server is from before versioning
+    ///
+    /// such servers already read frames and are treated as compatible,
+    /// servers from before framing never answer so the client is never created
+    pub fn protocol(&self) -> Option<u32> {
+        self.protocol
     }
 
-    /// send a request
-    /// private method as the convenience functions should be used instead
-    async fn send(&self, req: Request) -> ResponseContent {
-        let (tx, rx) = oneshot::channel();
-        self.req_confirms.lock().await.insert(req.id(), tx);
-        self.outbound_send.send(req).unwrap();
-        rx.await.unwrap()
+    /// optional features the server supports
+    pub fn capabilities(&self) -> &[Capability] {
+        &self.capabilities
     }
-}
 
-/// convenience functions
-impl Client {
-    pub async fn subscribe<T: Into<(Subscription, Option<u32>)>>(
-        &self,
-        channel: T,
-    ) -> ResponseContent {
-        let (channel, priority) = channel.into();
-        let req = Request::new(
-            Discriminator::default(),
-            RequestContent::Subscribe {
-                channel,
-                priority,
-                component: None,
-            },
-        );
-        self.send(req).await
+    /// check if the server supports an optional feature before using it
+    pub fn supports(&self, capability: &Capability) -> bool {
+        self.capabilities.contains(capability)
     }
 
-    pub async fn subscribe_multiple<T: Into<(Subscription, Option<u32>)>>(
-        &self,
-        channels: Vec<T>,
-    ) -> ResponseContent {
-        let req = Request::new(
-            Discriminator::default(),
-            RequestContent::Subscribe {
-                channel: Subscription::Multiple {
-                    subs: channels.into_iter().map(|item| item.into()).collect(),
-                },
-                priority: None,
-                component: None,
-            },
-        );
-        self.send(req).await
+    /// wait for the next event that has not been taken by a sub stream
+    pub async fn recv(&mut self) -> Option<Event> {
+        self.events.recv().await
     }
 
-    pub async fn unsubscribe(&self, channel: Subscription) -> ResponseContent {
-        let req = Request::new(
-            Discriminator::default(),
-            RequestContent::Unsubscribe {
-                channel,
-                component: None,
-            },
-        );
-        self.send(req).await
+    /// the main event stream, use `EventStream::split` to create sub streams
+    /// which can be consumed independently
+    pub fn events(&mut self) -> &mut EventStream {
+        &mut self.events
+    }
+
+    /// bind the listener socket, replacing a leftover socket file no one is listening on
+    fn bind_listener(path: &Path) -> Result<UnixListener, ClientError> {
+        let bind_error = |source| ClientError::Bind {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(bind_error)?;
+        }
+
+        match UnixListener::bind(path) {
+            // the socket was left behind by a client which did not exit cleanly,
+            // anything other than a socket is never removed
+            Err(e)
+                if e.kind() == ErrorKind::AddrInUse
+                    && std::fs::symlink_metadata(path)
+                        .is_ok_and(|meta| meta.file_type().is_socket())
+                    && UnixStream::connect(path).is_err() =>
+            {
+                let _ = std::fs::remove_file(path);
+                UnixListener::bind(path)
+            }
+            res => res,
+        }
+        .map_err(bind_error)
+    }
+
+    /// handle all responses from a single connection to the listener socket, in order
+    async fn listen(
+        mut stream: FrameReader<tokio::net::UnixStream>,
+        router: Arc<EventRouter>,
+        outbound_send: UnboundedSender<Request>,
+        req_confirms: Arc<ReqConfirms>,
+        ids: Arc<RequestIds>,
+    ) {
+        loop {
+            let res: Response = match stream.next().await {
+                Ok(Some(res)) => res,
+                // the frame was read in full, so the next one is still intact
+                Err(FrameError::Malformed(_)) => continue,
+                // either the connection has ended,
+                // or the stream is broken and no more frames can be read from it
+                Ok(None) | Err(_) => break,
+            };
+
+            match res.content {
+                // events have to be confirmed
+                ResponseContent::Event { content } => {
+                    // the receiving end is only gone once the client is dropped
+                    if router
+                        .route(Event::new(
+                            content,
+                            outbound_send.clone(),
+                            ids.clone(),
+                            res.id,
+                        ))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                // these are responses from canvas
+                // and dont have to be confirmed
+                // but their wait locks have to be released
+                // so the callers can know the task is done
+                ResponseContent::Error { .. }
+                | ResponseContent::Success { .. }
+                | ResponseContent::Undelivered => {
+                    if let Some(request) = res.request {
+                        req_confirms.resolve(request, Ok(res.content));
+                    }
+                }
+            }
+        }
+    }
+
+    /// write a request to the long lived connection
+    ///
+    /// the canvas may have closed the connection since the last request,
+    /// in that case the request is retried once on a new connection.
+    /// the canvas knows the listener socket per connection, so it is set again first
+    async fn write_persistent(
+        connection: &mut Option<tokio::net::UnixStream>,
+        request_socket: &Path,
+        req: &Request,
+        encoding: Encoding,
+        set_socket: &RequestContent,
+        ids: &RequestIds,
+    ) -> Result<(), ClientError> {
+        let bytes = encode_frame_with(req, encoding)?;
+
+        if let Some(stream) = connection {
+            if stream.write_all(bytes.as_slice()).await.is_ok() {
+                return Ok(());
+            }
+        }
+
+        *connection = None;
+        let mut stream = tokio::net::UnixStream::connect(request_socket)
+            .await
+            .map_err(|source| ClientError::Connect {
+                path: request_socket.to_path_buf(),
+                source,
+            })?;
+        if !matches!(req.content, RequestContent::SetSocket { .. }) {
+            // the set socket request is always sent as json, its response is not waited for
+            let set_socket = Request::new(Discriminator::default(), set_socket.clone(), ids);
+            stream
+                .write_all(encode_frame_with(&set_socket, Encoding::Json)?.as_slice())
+                .await?;
+        }
+        stream.write_all(bytes.as_slice()).await?;
+        *connection = Some(stream);
+        Ok(())
+    }
+}
+
+/// shutting down
+impl Client {
+    /// disconnect from the canvas
+    ///
+    /// flushes unflushed renders, releases events which were never received,
+    /// then waits for the canvas to drop this client before removing the listener socket
+    ///
+    /// the listener socket is removed even if the canvas could not be reached
+    pub async fn close(mut self) -> Result<(), ClientError> {
+        let res = self.shutdown().await;
+
+        self.closed = true;
+        self.listener_handle.abort();
+        self.request_handle.abort();
+        let _ = std::fs::remove_file(&self.listener_socket);
+        res
     }
 
-    pub async fn exit(&self) -> ResponseContent {
-        let req = Request::new(
+    async fn shutdown(&mut self) -> Result<(), ClientError> {
+        // the client is dropped from the canvas even if the last renders failed
+        let rendered = self.renderall().await;
+
+        // events which were never received are released, confirming them on drop.
+        // requests are written in order, so the confirmations reach the canvas before the drop
+        while self.events.try_recv().is_some() {}
+
+        let req = self.handle.request(
             Discriminator::default(),
-            RequestContent::Drop {
-                discrim: Some(Discriminator::new(vec![1])),
-            },
+            RequestContent::Drop { discrim: None },
         );
-        self.send(req).await
+        let res = self.handle.send(req).await;
+
+        // events which arrived in the meantime are released too
+        while self.events.try_recv().is_some() {}
+
+        let dropped = match res? {
+            ResponseContent::Success {
+                content: ResponseSuccess::Dropped,
+            } => Ok(()),
+            res => Err(ClientError::UnexpectedResponse(res)),
+        };
+        rendered.and(dropped)
     }
+}
 
+/// drawing functions, requests are only sent on renderall
+///
+/// characters are drawn into a frame buffer, and only the cells which changed since the last renderall are sent.
+/// styled characters, text, lines and borders are drawn with the functions of `Surface`.
+/// characters with attributes are drawn with colours only if the server does not support them
+impl Client {
     pub fn setchar(&mut self, x: u32, y: u32, c: char) {
-        self.render_requests.push(RenderRequest::setchar(x, y, c))
+        self.frame.set(x, y, Cell::new(c))
     }
 
     pub fn setcharcoloured(&mut self, x: u32, y: u32, c: char, fg: Colour, bg: Colour) {
-        self.render_requests
-            .push(RenderRequest::setchar_coloured(x, y, c, fg, bg))
+        self.frame.set(x, y, Cell::coloured(c, fg, bg))
     }
 
     pub fn setcursorstyle(&mut self, style: CursorStyle) {
@@ -245,106 +408,170 @@ impl Client {
         self.render_requests.push(RenderRequest::HideCursor)
     }
 
-    pub async fn renderall(&mut self) -> ResponseContent {
-        if self.render_requests.is_empty() {
-            return ResponseContent::Success {
-                content: ResponseSuccess::Rendered,
-            };
+    /// send every cell again on the next renderall, even those which did not change
+    ///
+    /// use this when something else has drawn over the component, such as when its space is focused again
+    pub fn invalidate(&mut self) {
+        self.frame.invalidate()
+    }
+
+    /// cells drawn so far
+    pub fn frame(&self) -> &FrameBuffer {
+        &self.frame
+    }
+
+    pub async fn renderall(&mut self) -> Result<(), ClientError> {
+        let tasks = self.take_renders();
+        if tasks.is_empty() {
+            return Ok(());
         }
 
-        let req = Request::new(
-            Discriminator::default(),
-            RequestContent::Render {
-                flush: true,
-                content: RenderRequest::RenderMultiple {
-                    tasks: std::mem::take(&mut self.render_requests),
-                },
-            },
-        );
-        self.send(req).await
+        let res = self.handle.render(tasks).await;
+        if res.is_err() {
+            // it is unknown what the canvas shows now
+            self.frame.invalidate();
+        }
+        res
     }
 
-    pub async fn spawn_at(
+    /// changed cells followed by all other unflushed render requests
+    ///
+    /// styled characters are sent with colours only if the server does not support attributes
+    fn take_renders(&mut self) -> Vec<RenderRequest> {
+        let mut tasks = self.frame.flush();
+        tasks.append(&mut self.render_requests);
+
+        if !self.supports(&Capability::STYLED_CHARS) {
+            tasks = tasks.into_iter().map(RenderRequest::colour_only).collect();
+        }
+        tasks
+    }
+}
+
+impl Surface for Client {
+    fn set(&mut self, x: u32, y: u32, cell: Cell) {
+        self.frame.set(x, y, cell)
+    }
+
+    fn get(&self, x: u32, y: u32) -> Option<Cell> {
+        self.frame.get(x, y).copied()
+    }
+
+    /// the client does not know the size of the terminal
+    fn size(&self) -> Option<(u32, u32)> {
+        None
+    }
+}
+
+/// request functions, the same as those of `ClientHandle`
+impl Client {
+    /// a handle that waits for responses for a different amount of time,
+    /// `None` waits forever
+    pub fn with_timeout(&self, timeout: Option<Duration>) -> ClientHandle {
+        self.handle.with_timeout(timeout)
+    }
+
+    /// how long requests sent from the client wait for a response
+    pub fn timeout(&self) -> Option<Duration> {
+        self.handle.timeout()
+    }
+
+    /// create a request with an id unique to this client
+    pub fn request(&self, target: Discriminator, content: RequestContent) -> Request {
+        self.handle.request(target, content)
+    }
+
+    pub fn subscribe<T: Into<(Subscription, Option<u32>)>>(
+        &self,
+        channel: T,
+    ) -> PendingResponse<()> {
+        self.handle.subscribe(channel)
+    }
+
+    pub fn subscribe_multiple<T: Into<(Subscription, Option<u32>)>>(
+        &self,
+        channels: Vec<T>,
+    ) -> PendingResponse<()> {
+        self.handle.subscribe_multiple(channels)
+    }
+
+    pub fn unsubscribe(&self, channel: Subscription) -> PendingResponse<()> {
+        self.handle.unsubscribe(channel)
+    }
+
+    pub fn exit(&self) -> PendingResponse<()> {
+        self.handle.exit()
+    }
+
+    pub fn spawn_at(
         &self,
         label: String,
         command: String,
         args: Vec<String>,
         parent: Discriminator,
-    ) -> ResponseContent {
-        let req = Request::new(
-            parent,
-            RequestContent::Spawn {
-                command,
-                args,
-                label,
-            },
-        );
-        self.send(req).await
+    ) -> PendingResponse<Discriminator> {
+        self.handle.spawn_at(label, command, args, parent)
     }
 
-    pub async fn spawn(
+    pub fn spawn(
         &self,
         label: String,
         command: String,
         args: Vec<String>,
-    ) -> ResponseContent {
-        let req = Request::new(
-            Discriminator::default(),
-            RequestContent::Spawn {
-                command,
-                args,
-                label,
-            },
-        );
-        self.send(req).await
+    ) -> PendingResponse<Discriminator> {
+        self.handle.spawn(label, command, args)
     }
 
-    pub async fn focus_at(&self, discrim: Discriminator) -> ResponseContent {
-        let req = Request::new(discrim, RequestContent::FocusAt);
-        self.send(req).await
+    pub fn focus_at(&self, discrim: Discriminator) -> PendingResponse<()> {
+        self.handle.focus_at(discrim)
     }
 
-    pub async fn new_space(&self, parent: Discriminator, label: String) -> ResponseContent {
-        let req = Request::new(parent, RequestContent::NewSpace { label });
-        self.send(req).await
+    pub fn new_space(
+        &self,
+        parent: Discriminator,
+        label: String,
+    ) -> PendingResponse<Discriminator> {
+        self.handle.new_space(parent, label)
     }
 
-    pub async fn message(&self, target: Discriminator, content: String) -> ResponseContent {
-        let req = Request::new(
-            target.clone(),
-            RequestContent::Message {
-                content,
-                sender: Discriminator::default(),
-                target,
-            },
-        );
-        self.send(req).await
+    pub fn message(&self, target: Discriminator, content: String) -> PendingResponse<()> {
+        self.handle.message(target, content)
     }
 
-    pub async fn broadcast(&self, content: String) -> ResponseContent {
-        let req = Request::new(
-            Discriminator::master(),
-            RequestContent::Message {
-                content,
-                sender: Discriminator::default(),
-                target: Discriminator::master(),
-            },
-        );
-        self.send(req).await
+    pub fn broadcast(&self, content: String) -> PendingResponse<()> {
+        self.handle.broadcast(content)
+    }
+
+    /// render a batch of requests at once, and flush the terminal
+    ///
+    /// use `renderall` to send what has been drawn on the client
+    pub fn render(&self, tasks: Vec<RenderRequest>) -> PendingResponse<()> {
+        self.handle.render(tasks)
     }
 }
 
 impl Drop for Client {
+    /// best effort fallback for when `close()` was not called
+    ///
+    /// unflushed renders and the drop request are queued without waiting,
+    /// they are only sent if the runtime keeps running for long enough
     fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+
         self.listener_handle.abort();
-        self.request_handle.abort();
-        let req = Request::new(
+        // events which were never received are released
+        while self.events.try_recv().is_some() {}
+        let tasks = self.take_renders();
+        if !tasks.is_empty() {
+            drop(self.handle.render(tasks));
+        }
+        // the request loop stops by itself once this is written
+        drop(self.handle.send(self.handle.request(
             Discriminator::default(),
             RequestContent::Drop { discrim: None },
-        );
-        UnixStream::connect(self.request_socket.clone())
-            .unwrap()
-            .write_all(serde_json::to_vec(&req).unwrap().as_slice())
-            .unwrap();
+        )));
+        let _ = std::fs::remove_file(&self.listener_socket);
     }
 }
@@ -1,17 +1,106 @@
-use std::path::PathBuf;
+use std::{
+    ffi::OsString,
+    path::PathBuf,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+/// environment variable the canvas can use to pass the listener socket path
+pub const LISTENER_SOCKET_ENV: &str = "CCANVAS_LISTENER_SOCKET";
+/// environment variable the canvas can use to pass the request socket path
+pub const REQUEST_SOCKET_ENV: &str = "CCANVAS_REQUEST_SOCKET";
+/// argument the canvas can use to pass the listener socket path, takes priority over the env
+pub const LISTENER_SOCKET_ARG: &str = "--ccanvas-listener-socket";
+/// argument the canvas can use to pass the request socket path, takes priority over the env
+pub const REQUEST_SOCKET_ARG: &str = "--ccanvas-request-socket";
+/// how long requests wait for a response unless configured otherwise
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
 pub struct ClientConfig {
     /// path to the listener socket
     pub listener_socket: PathBuf,
     /// path to request socket
     pub request_socket: PathBuf,
+    /// how long to wait for a response before giving up, forever if none
+    ///
+    /// can be overridden per call with `ClientHandle::with_timeout`
+    pub request_timeout: Option<Duration>,
 }
 
 impl Default for ClientConfig {
+    /// reads the socket paths from the process args and env,
+    /// generating a unique listener socket if none is given
     fn default() -> Self {
+        Self::from_env().with_args(std::env::args_os())
+    }
+}
+
+impl ClientConfig {
+    /// reads the socket paths from env,
+    /// falling back to `requests.sock` and a unique listener socket
+    pub fn from_env() -> Self {
         Self {
-            listener_socket: PathBuf::from("listen.sock"),
-            request_socket: PathBuf::from("requests.sock"),
+            listener_socket: std::env::var_os(LISTENER_SOCKET_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(Self::unique_listener_socket),
+            request_socket: std::env::var_os(REQUEST_SOCKET_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("requests.sock")),
+            request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
         }
     }
+
+    /// wait for responses for a different amount of time, `None` waits forever
+    pub fn with_request_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// override the socket paths with those found in args,
+    /// both `--ccanvas-listener-socket path` and `--ccanvas-listener-socket=path` are accepted
+    ///
+    /// unrelated args are ignored
+    pub fn with_args<I: IntoIterator<Item = T>, T: Into<OsString>>(mut self, args: I) -> Self {
+        let mut args = args.into_iter().map(Into::into);
+
+        while let Some(arg) = args.next() {
+            let arg = match arg.into_string() {
+                Ok(arg) => arg,
+                Err(_) => continue,
+            };
+
+            let (key, value) = match arg.split_once('=') {
+                Some((key, value)) => (key.to_string(), Some(OsString::from(value))),
+                None => (arg, None),
+            };
+
+            let field = match key.as_str() {
+                LISTENER_SOCKET_ARG => &mut self.listener_socket,
+                REQUEST_SOCKET_ARG => &mut self.request_socket,
+                _ => continue,
+            };
+
+            if let Some(value) = value.or_else(|| args.next()) {
+                *field = PathBuf::from(value);
+            }
+        }
+
+        self
+    }
+
+    /// a listener socket path which is not used by any other client,
+    /// in `$XDG_RUNTIME_DIR/ccanvas`, or the temp dir if there is no runtime dir
+    pub fn unique_listener_socket() -> PathBuf {
+        static COUNT: AtomicU32 = AtomicU32::new(0);
+
+        std::env::var_os("XDG_RUNTIME_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir)
+            .join("ccanvas")
+            .join(format!(
+                "listen-{}-{}.sock",
+                std::process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed)
+            ))
+    }
 }
@@ -0,0 +1,89 @@
+use std::{fmt::Display, io, path::PathBuf, time::Duration};
+
+use crate::bindings::{FrameError, ResponseContent, ResponseError};
+
+/// everything that can go wrong when talking to the canvas
+#[derive(Debug)]
+pub enum ClientError {
+    /// the listener socket could not be created, usually because the file already exists
+    Bind { path: PathBuf, source: io::Error },
+    /// the request socket could not be reached, usually because the canvas is not running
+    Connect { path: PathBuf, source: io::Error },
+    /// reading from or writing to a socket failed
+    Io(io::Error),
+    /// a request could not be encoded into a frame
+    Frame(FrameError),
+    /// the canvas speaks a version of the protocol these bindings cannot understand
+    Incompatible { client: u32, server: u32 },
+    /// the canvas could not carry out the request
+    Response(ResponseError),
+    /// the request did not reach its target, usually because it no longer exists
+    Undelivered,
+    /// the canvas responded with something that does not make sense for the request
+    UnexpectedResponse(ResponseContent),
+    /// the canvas did not respond to a request in time,
+    /// the request may still be carried out but its response is ignored
+    Timeout { id: u32, after: Duration },
+    /// the background tasks of the client have stopped,
+    /// no more requests can be sent and no more responses will arrive
+    Disconnected,
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bind { path, source } => write!(
+                f,
+                "failed to bind listener socket at {}: {source}",
+                path.display()
+            ),
+            Self::Connect { path, source } => write!(
+                f,
+                "failed to connect to request socket at {}: {source}",
+                path.display()
+            ),
+            Self::Io(e) => write!(f, "socket io error: {e}"),
+            Self::Frame(e) => write!(f, "failed to encode request: {e}"),
+            Self::Incompatible { client, server } => write!(
+                f,
+                "canvas uses protocol version {server}, but this client only supports version {client}"
+            ),
+            Self::Response(e) => write!(f, "canvas responded with an error: {e:?}"),
+            Self::Undelivered => f.write_str("request was not delivered to its target"),
+            Self::UnexpectedResponse(res) => write!(f, "unexpected response from canvas: {res:?}"),
+            Self::Timeout { id, after } => {
+                write!(f, "request {id} timed out after {after:?}")
+            }
+            Self::Disconnected => f.write_str("client is disconnected from the canvas"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Bind { source, .. } | Self::Connect { source, .. } | Self::Io(source) => {
+                Some(source)
+            }
+            Self::Frame(e) => Some(e),
+            Self::Incompatible { .. }
+            | Self::Response(_)
+            | Self::Undelivered
+            | Self::UnexpectedResponse(_)
+            | Self::Timeout { .. }
+            | Self::Disconnected => None,
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<FrameError> for ClientError {
+    fn from(value: FrameError) -> Self {
+        Self::Frame(value)
+    }
+}
@@ -0,0 +1,270 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::bindings::{
+    Discriminator, RenderRequest, Request, RequestContent, RequestIds, ResponseContent,
+    ResponseSuccess, Subscription,
+};
+
+use super::{ClientError, PendingResponse, ReqConfirms};
+
+/// a cheap, cloneable handle for sending requests to the canvas
+///
+/// can be moved into other tasks, so they can send messages and render
+/// while the owning `Client` is waiting for events
+#[derive(Clone)]
+pub struct ClientHandle {
+    /// request to ccanvas
+    outbound_send: UnboundedSender<Request>,
+    /// confirmation handles for requests
+    req_confirms: Arc<ReqConfirms>,
+    /// request id allocator
+    ids: Arc<RequestIds>,
+    /// how long to wait for a response, forever if none
+    timeout: Option<Duration>,
+}
+
+impl ClientHandle {
+    pub(crate) fn new(
+        outbound_send: UnboundedSender<Request>,
+        req_confirms: Arc<ReqConfirms>,
+        ids: Arc<RequestIds>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            outbound_send,
+            req_confirms,
+            ids,
+            timeout,
+        }
+    }
+
+    /// a handle that waits for responses for a different amount of time,
+    /// `None` waits forever
+    ///
+    /// `client.with_timeout(Some(Duration::from_secs(1))).spawn(..)` overrides the timeout for a single call
+    pub fn with_timeout(&self, timeout: Option<Duration>) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
+        }
+    }
+
+    /// how long requests sent from this handle wait for a response
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// create a request with an id unique to this client
+    pub fn request(&self, target: Discriminator, content: RequestContent) -> Request {
+        Request::new(target, content, &self.ids)
+    }
+
+    /// queue a request, it is sent straight away without waiting for the returned future
+    /// private method as the convenience functions should be used instead
+    pub(crate) fn send(&self, req: Request) -> PendingResponse {
+        let id = req.id();
+        // dropping the confirmation removes its entry,
+        // so nothing is left behind if the response is ignored, cancelled or times out
+        let confirmation = self.req_confirms.insert(id);
+
+        if self.outbound_send.send(req).is_err() {
+            return PendingResponse::failed(ClientError::Disconnected);
+        }
+
+        PendingResponse::new(id, confirmation, self.timeout)
+    }
+}
+
+/// convenience functions
+///
+/// each request is queued as soon as the function is called,
+/// so many requests can be sent before awaiting any of the responses,
+/// or the response can be dropped without awaiting it at all
+impl ClientHandle {
+    pub fn subscribe<T: Into<(Subscription, Option<u32>)>>(
+        &self,
+        channel: T,
+    ) -> PendingResponse<()> {
+        let (channel, priority) = channel.into();
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Subscribe {
+                channel,
+                priority,
+                component: None,
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::SubscribeAdded))
+    }
+
+    pub fn subscribe_multiple<T: Into<(Subscription, Option<u32>)>>(
+        &self,
+        channels: Vec<T>,
+    ) -> PendingResponse<()> {
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Subscribe {
+                channel: Subscription::Multiple {
+                    subs: channels.into_iter().map(|item| item.into()).collect(),
+                },
+                priority: None,
+                component: None,
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::SubscribeAdded))
+    }
+
+    pub fn unsubscribe(&self, channel: Subscription) -> PendingResponse<()> {
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Unsubscribe {
+                channel,
+                component: None,
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::SubscribeRemoved))
+    }
+
+    pub fn exit(&self) -> PendingResponse<()> {
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Drop {
+                discrim: Some(Discriminator::new(vec![1])),
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::Dropped))
+    }
+
+    pub fn spawn_at(
+        &self,
+        label: String,
+        command: String,
+        args: Vec<String>,
+        parent: Discriminator,
+    ) -> PendingResponse<Discriminator> {
+        let req = self.request(
+            parent,
+            RequestContent::Spawn {
+                command,
+                args,
+                label,
+            },
+        );
+        self.send(req).map(spawned)
+    }
+
+    pub fn spawn(
+        &self,
+        label: String,
+        command: String,
+        args: Vec<String>,
+    ) -> PendingResponse<Discriminator> {
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Spawn {
+                command,
+                args,
+                label,
+            },
+        );
+        self.send(req).map(spawned)
+    }
+
+    pub fn focus_at(&self, discrim: Discriminator) -> PendingResponse<()> {
+        let req = self.request(discrim, RequestContent::FocusAt);
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::FocusChanged))
+    }
+
+    pub fn new_space(
+        &self,
+        parent: Discriminator,
+        label: String,
+    ) -> PendingResponse<Discriminator> {
+        let req = self.request(parent, RequestContent::NewSpace { label });
+        self.send(req).map(space_created)
+    }
+
+    pub fn message(&self, target: Discriminator, content: String) -> PendingResponse<()> {
+        let req = self.request(
+            target.clone(),
+            RequestContent::Message {
+                content,
+                sender: Discriminator::default(),
+                target,
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::MessageDelivered))
+    }
+
+    pub fn broadcast(&self, content: String) -> PendingResponse<()> {
+        let req = self.request(
+            Discriminator::master(),
+            RequestContent::Message {
+                content,
+                sender: Discriminator::default(),
+                target: Discriminator::master(),
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::MessageDelivered))
+    }
+
+    /// render a batch of requests at once, and flush the terminal
+    pub fn render(&self, tasks: Vec<RenderRequest>) -> PendingResponse<()> {
+        let req = self.request(
+            Discriminator::default(),
+            RequestContent::Render {
+                flush: true,
+                content: RenderRequest::RenderMultiple { tasks },
+            },
+        );
+        self.send(req)
+            .map(|res| expect(res, ResponseSuccess::Rendered))
+    }
+}
+
+/// the success of a response, or the reason there is none
+fn success(res: ResponseContent) -> Result<ResponseSuccess, ClientError> {
+    match res {
+        ResponseContent::Success { content } => Ok(content),
+        ResponseContent::Error { content } => Err(ClientError::Response(content)),
+        ResponseContent::Undelivered => Err(ClientError::Undelivered),
+        res => Err(ClientError::UnexpectedResponse(res)),
+    }
+}
+
+/// the response has to be the expected success
+fn expect(res: ResponseContent, expected: ResponseSuccess) -> Result<(), ClientError> {
+    match success(res)? {
+        content if content == expected => Ok(()),
+        content => Err(ClientError::UnexpectedResponse(ResponseContent::Success {
+            content,
+        })),
+    }
+}
+
+fn spawned(res: ResponseContent) -> Result<Discriminator, ClientError> {
+    match success(res)? {
+        ResponseSuccess::Spawned { discrim } => Ok(discrim),
+        content => Err(ClientError::UnexpectedResponse(ResponseContent::Success {
+            content,
+        })),
+    }
+}
+
+fn space_created(res: ResponseContent) -> Result<Discriminator, ClientError> {
+    match success(res)? {
+        ResponseSuccess::SpaceCreated { discrim } => Ok(discrim),
+        content => Err(ClientError::UnexpectedResponse(ResponseContent::Success {
+            content,
+        })),
+    }
+}
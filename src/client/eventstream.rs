@@ -0,0 +1,151 @@
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    bindings::{Discriminator, Event, EventVariant},
+    util::lock,
+};
+
+/// decides which events go to a sub stream
+#[derive(Clone)]
+pub enum EventFilter {
+    /// all key presses
+    Keys,
+    /// all mouse events
+    Mouse,
+    /// all messages from other components
+    Messages,
+    /// messages from a specific component
+    MessagesFrom(Discriminator),
+    /// screen resizes
+    Resize,
+    /// both focused and unfocused
+    Focus,
+    /// any event the function returns true for
+    Custom(Arc<dyn Fn(&EventVariant) -> bool + Send + Sync>),
+}
+
+impl EventFilter {
+    pub fn custom<F: Fn(&EventVariant) -> bool + Send + Sync + 'static>(f: F) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    pub fn matches(&self, event: &EventVariant) -> bool {
+        match (self, event) {
+            (Self::Keys, EventVariant::Key(_))
+            | (Self::Mouse, EventVariant::Mouse(_))
+            | (Self::Messages, EventVariant::Message { .. })
+            | (Self::Resize, EventVariant::Resize { .. })
+            | (Self::Focus, EventVariant::Focused | EventVariant::Unfocused) => true,
+            (Self::MessagesFrom(source), EventVariant::Message { sender, .. }) => source == sender,
+            (Self::Custom(f), event) => f(event),
+            _ => false,
+        }
+    }
+}
+
+impl Debug for EventFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Keys => f.write_str("Keys"),
+            Self::Mouse => f.write_str("Mouse"),
+            Self::Messages => f.write_str("Messages"),
+            Self::MessagesFrom(source) => f.debug_tuple("MessagesFrom").field(source).finish(),
+            Self::Resize => f.write_str("Resize"),
+            Self::Focus => f.write_str("Focus"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// passes each event to the first sub stream that wants it,
+/// or to the main stream if none of them do
+pub(crate) struct EventRouter {
+    main: UnboundedSender<Event>,
+    routes: Mutex<Vec<(EventFilter, UnboundedSender<Event>)>>,
+}
+
+impl EventRouter {
+    /// errors only if the main stream has been dropped
+    pub fn route(&self, event: Event) -> Result<(), Event> {
+        let mut routes = lock(&self.routes);
+        // sub streams that have been dropped no longer take events
+        routes.retain(|(_, sender)| !sender.is_closed());
+
+        let event = match routes
+            .iter()
+            .find(|(filter, _)| filter.matches(event.get()))
+        {
+            Some((_, sender)) => match sender.send(event) {
+                Ok(()) => return Ok(()),
+                // the sub stream was dropped after the check above
+                Err(e) => e.0,
+            },
+            None => event,
+        };
+        self.main.send(event).map_err(|e| e.0)
+    }
+}
+
+/// a stream of events, sub streams can be split off to receive some of the events instead
+///
+/// once a sub stream is dropped, its events go back to the main stream
+pub struct EventStream {
+    recv: UnboundedReceiver<Event>,
+    router: Arc<EventRouter>,
+}
+
+impl EventStream {
+    /// create the main stream, along with a new router
+    pub(crate) fn new() -> Self {
+        let (main, recv) = mpsc::unbounded_channel();
+        Self {
+            recv,
+            router: Arc::new(EventRouter {
+                main,
+                routes: Mutex::default(),
+            }),
+        }
+    }
+
+    /// the router which feeds this stream
+    pub(crate) fn router(&self) -> Arc<EventRouter> {
+        self.router.clone()
+    }
+
+    /// wait for the next event
+    pub async fn recv(&mut self) -> Option<Event> {
+        self.recv.recv().await
+    }
+
+    /// take the next event if one has already arrived, without waiting
+    pub fn try_recv(&mut self) -> Option<Event> {
+        self.recv.try_recv().ok()
+    }
+
+    /// create a sub stream that receives all future events matching the filter,
+    /// these events no longer appear in any stream split off before it or in the main stream
+    pub fn split(&self, filter: EventFilter) -> EventStream {
+        let (sender, recv) = mpsc::unbounded_channel();
+        lock(&self.router.routes).insert(0, (filter, sender));
+        EventStream {
+            recv,
+            router: self.router.clone(),
+        }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.recv.poll_recv(cx)
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::bindings::{Cell, RenderRequest};
+
+use super::char_width;
+
+/// cells drawn by the client, so only the ones which changed are sent to the canvas
+#[derive(Clone, Default, Debug)]
+pub struct FrameBuffer {
+    /// what the canvas shows, as far as the client knows
+    shown: HashMap<(u32, u32), Cell>,
+    /// drawn since the last flush, keyed by row first so they are sent in reading order
+    drawn: BTreeMap<(u32, u32), Cell>,
+}
+
+impl FrameBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// draw a cell, replacing anything drawn there since the last flush
+    ///
+    /// wide characters also cover the next column, which is left for the terminal to draw.
+    /// drawing over either half of a wide character erases all of it, like a terminal does
+    pub fn set(&mut self, x: u32, y: u32, cell: Cell) {
+        if let Some(left) = x.checked_sub(1) {
+            if self
+                .get(left, y)
+                .is_some_and(|left| char_width(left.c) == 2)
+            {
+                self.forget(left, y);
+            }
+        }
+
+        self.drawn.insert((y, x), cell);
+
+        if char_width(cell.c) == 2 {
+            if let Some(right) = x.checked_add(1) {
+                self.forget(right, y);
+            }
+        }
+    }
+
+    /// no longer known, so the next cell drawn there is always sent
+    fn forget(&mut self, x: u32, y: u32) {
+        self.drawn.remove(&(y, x));
+        self.shown.remove(&(y, x));
+    }
+
+    /// the cell as it will be after the next flush, none if nothing has been drawn there
+    pub fn get(&self, x: u32, y: u32) -> Option<&Cell> {
+        self.drawn.get(&(y, x)).or_else(|| self.shown.get(&(y, x)))
+    }
+
+    /// whether the next flush has anything to send
+    pub fn is_dirty(&self) -> bool {
+        self.drawn
+            .iter()
+            .any(|(pos, cell)| self.shown.get(pos) != Some(cell))
+    }
+
+    /// forget what the canvas shows, so the next flush sends every cell again
+    ///
+    /// needed when something else has drawn over the component, such as another space
+    pub fn invalidate(&mut self) {
+        for (pos, cell) in self.shown.drain() {
+            self.drawn.entry(pos).or_insert(cell);
+        }
+    }
+
+    /// the requests which bring the canvas up to date, the cells are assumed to be shown afterwards
+    ///
+    /// cells drawn with the same content as is already shown are left out
+    pub fn flush(&mut self) -> Vec<RenderRequest> {
+        let mut tasks = Vec::new();
+
+        for ((y, x), cell) in std::mem::take(&mut self.drawn) {
+            if self.shown.insert((y, x), cell) != Some(cell) {
+                tasks.push(cell.to_request(x, y));
+            }
+        }
+
+        tasks
+    }
+}
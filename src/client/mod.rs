@@ -1,5 +1,35 @@
 mod client;
 pub use client::*;
 
+mod clienthandle;
+pub use clienthandle::*;
+
+mod eventstream;
+pub use eventstream::*;
+
+mod pendingresponse;
+pub use pendingresponse::*;
+
+mod framebuffer;
+pub use framebuffer::*;
+
+mod text;
+pub use text::*;
+
+mod border;
+pub use border::*;
+
+mod surface;
+pub use surface::*;
+
+mod region;
+pub use region::*;
+
 mod clientconfig;
 pub use clientconfig::*;
+
+mod clienterror;
+pub use clienterror::*;
+
+mod reqconfirms;
+pub(crate) use reqconfirms::*;
@@ -0,0 +1,100 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use tokio::time::{Instant, Sleep};
+
+use crate::bindings::ResponseContent;
+
+use super::{ClientError, Confirmation};
+
+/// the response to a request which has already been queued
+///
+/// await it to get the response, or drop it to ignore the response,
+/// the request is sent either way
+pub struct PendingResponse<T = ResponseContent> {
+    state: State,
+    /// turns the raw response into the result the caller wants
+    map: fn(ResponseContent) -> Result<T, ClientError>,
+    /// when the request was queued, timeouts are counted from here
+    sent: Instant,
+    timeout: Option<Duration>,
+    /// started on the first poll, so no runtime is needed to queue a request
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+enum State {
+    Waiting { id: u32, confirmation: Confirmation },
+    Failed(ClientError),
+    Done,
+}
+
+impl PendingResponse {
+    pub(crate) fn new(id: u32, confirmation: Confirmation, timeout: Option<Duration>) -> Self {
+        Self {
+            state: State::Waiting { id, confirmation },
+            map: Ok,
+            sent: Instant::now(),
+            timeout,
+            sleep: None,
+        }
+    }
+
+    /// a response that is already known to have failed, such as when the client is disconnected
+    pub(crate) fn failed(e: ClientError) -> Self {
+        Self {
+            state: State::Failed(e),
+            map: Ok,
+            sent: Instant::now(),
+            timeout: None,
+            sleep: None,
+        }
+    }
+
+    /// resolve with the mapped response instead
+    pub(crate) fn map<U>(
+        self,
+        map: fn(ResponseContent) -> Result<U, ClientError>,
+    ) -> PendingResponse<U> {
+        PendingResponse {
+            state: self.state,
+            map,
+            sent: self.sent,
+            timeout: self.timeout,
+            sleep: self.sleep,
+        }
+    }
+}
+
+impl<T> Future for PendingResponse<T> {
+    type Output = Result<T, ClientError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let (id, mut confirmation) = match std::mem::replace(&mut this.state, State::Done) {
+            State::Waiting { id, confirmation } => (id, confirmation),
+            State::Failed(e) => return Poll::Ready(Err(e)),
+            State::Done => panic!("PendingResponse polled after completion"),
+        };
+
+        // the confirmation is dropped once ready, releasing its entry straight away
+        if let Poll::Ready(res) = Pin::new(&mut confirmation).poll(cx) {
+            return Poll::Ready(res.and_then(this.map));
+        }
+
+        if let Some(after) = this.timeout {
+            let sleep = this
+                .sleep
+                .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(this.sent + after)));
+            if sleep.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(ClientError::Timeout { id, after }));
+            }
+        }
+
+        this.state = State::Waiting { id, confirmation };
+        Poll::Pending
+    }
+}
@@ -0,0 +1,69 @@
+use crate::bindings::Cell;
+
+use super::{char_width, Surface};
+
+/// a rectangular part of a surface, drawn on in its own coordinates
+///
+/// anything drawn outside of the region is left out, so widgets can be placed anywhere
+/// without knowing where they are
+pub struct Region<'a, S: Surface + ?Sized> {
+    surface: &'a mut S,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl<'a, S: Surface + ?Sized> Region<'a, S> {
+    /// a region of the surface, cut down to fit inside of it
+    pub fn new(surface: &'a mut S, x: u32, y: u32, width: u32, height: u32) -> Self {
+        let (width, height) = match surface.size() {
+            Some((outer_width, outer_height)) => (
+                width.min(outer_width.saturating_sub(x)),
+                height.min(outer_height.saturating_sub(y)),
+            ),
+            None => (width, height),
+        };
+
+        Self {
+            surface,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// position of the region on the surface it is part of
+    pub fn origin(&self) -> (u32, u32) {
+        (self.x, self.y)
+    }
+
+    /// the position on the outer surface, none if it is outside of the region
+    fn translate(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        (x < self.width && y < self.height)
+            .then(|| self.x.checked_add(x).zip(self.y.checked_add(y)))
+            .flatten()
+    }
+}
+
+impl<S: Surface + ?Sized> Surface for Region<'_, S> {
+    /// wide characters which do not fit entirely are left out too
+    fn set(&mut self, x: u32, y: u32, cell: Cell) {
+        if x.saturating_add(char_width(cell.c).max(1)) > self.width {
+            return;
+        }
+        if let Some((x, y)) = self.translate(x, y) {
+            self.surface.set(x, y, cell)
+        }
+    }
+
+    fn get(&self, x: u32, y: u32) -> Option<Cell> {
+        let (x, y) = self.translate(x, y)?;
+        self.surface.get(x, y)
+    }
+
+    fn size(&self) -> Option<(u32, u32)> {
+        Some((self.width, self.height))
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use tokio::sync::oneshot;
+
+use crate::{bindings::ResponseContent, util::lock};
+
+use super::ClientError;
+
+type Confirm = oneshot::Sender<Result<ResponseContent, ClientError>>;
+
+/// confirmation handles for requests, resolved by the listener loop,
+/// or by the request loop if the request could not be sent
+///
+/// the lock is never held across an await, so a blocking mutex is cheaper than an async one
+#[derive(Default)]
+pub struct ReqConfirms(Mutex<HashMap<u32, Confirm>>);
+
+impl ReqConfirms {
+    /// register a request id, returns a future which resolves with the response
+    ///
+    /// the entry is removed once the future is dropped, even if no response arrived
+    pub fn insert(self: &Arc<Self>, id: u32) -> Confirmation {
+        let (tx, rx) = oneshot::channel();
+        lock(&self.0).insert(id, tx);
+        Confirmation {
+            id,
+            rx,
+            confirms: self.clone(),
+        }
+    }
+
+    /// release whoever is waiting on the request, does nothing if no one is
+    pub fn resolve(&self, id: u32, res: Result<ResponseContent, ClientError>) {
+        if let Some(entry) = lock(&self.0).remove(&id) {
+            // the caller may have stopped waiting, which is fine
+            let _ = entry.send(res);
+        }
+    }
+
+    /// forget about a request without resolving it
+    pub fn remove(&self, id: u32) {
+        lock(&self.0).remove(&id);
+    }
+}
+
+/// resolves with the response to a single request
+pub struct Confirmation {
+    id: u32,
+    rx: oneshot::Receiver<Result<ResponseContent, ClientError>>,
+    confirms: Arc<ReqConfirms>,
+}
+
+impl Future for Confirmation {
+    type Output = Result<ResponseContent, ClientError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx)
+            .poll(cx)
+            .map(|res| res.unwrap_or(Err(ClientError::Disconnected)))
+    }
+}
+
+impl Drop for Confirmation {
+    /// a timed out or cancelled request will never be waited on again
+    fn drop(&mut self) {
+        self.confirms.remove(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::PendingResponse;
+
+    use super::*;
+
+    fn len(confirms: &ReqConfirms) -> usize {
+        lock(&confirms.0).len()
+    }
+
+    #[tokio::test]
+    async fn timed_out_requests_leave_no_entry() {
+        let confirms = Arc::new(ReqConfirms::default());
+        let pending = PendingResponse::new(1, confirms.insert(1), Some(Duration::from_millis(10)));
+        assert_eq!(len(&confirms), 1);
+
+        assert!(matches!(
+            pending.await,
+            Err(ClientError::Timeout { id: 1, .. })
+        ));
+        assert_eq!(len(&confirms), 0);
+    }
+
+    #[test]
+    fn dropped_requests_leave_no_entry() {
+        let confirms = Arc::new(ReqConfirms::default());
+        let pending = PendingResponse::new(1, confirms.insert(1), None);
+        assert_eq!(len(&confirms), 1);
+
+        drop(pending);
+        assert_eq!(len(&confirms), 0);
+    }
+}
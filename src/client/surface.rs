@@ -0,0 +1,150 @@
+use crate::bindings::{Cell, Colour, Style};
+
+use super::{char_width, Arms, BorderStyle, Region, TextStyle};
+
+/// something which can be drawn on, such as the client or a region of it
+///
+/// positions are relative to the top left corner of the surface,
+/// anything drawn outside of its size is left out
+pub trait Surface {
+    /// draw a single cell
+    fn set(&mut self, x: u32, y: u32, cell: Cell);
+
+    /// the cell drawn at the position, none if nothing has been drawn there
+    fn get(&self, x: u32, y: u32) -> Option<Cell>;
+
+    /// width and height, none if there is no limit
+    fn size(&self) -> Option<(u32, u32)>;
+
+    fn setchar(&mut self, x: u32, y: u32, c: char) {
+        self.set(x, y, Cell::new(c))
+    }
+
+    fn setcharcoloured(&mut self, x: u32, y: u32, c: char, fg: Colour, bg: Colour) {
+        self.set(x, y, Cell::coloured(c, fg, bg))
+    }
+
+    fn setcharstyled(&mut self, x: u32, y: u32, c: char, style: Style) {
+        self.set(x, y, Cell::styled(c, style))
+    }
+
+    /// a part of this surface, with its top left corner at the position
+    ///
+    /// the region is cut down to fit inside this surface, and can be split into regions of its own
+    fn region(&mut self, x: u32, y: u32, width: u32, height: u32) -> Region<'_, Self>
+    where
+        Self: Sized,
+    {
+        Region::new(self, x, y, width, height)
+    }
+
+    /// draw text starting at the position, each line on the next row
+    ///
+    /// returns the number of rows drawn
+    fn print(&mut self, x: u32, y: u32, text: &str) -> u32 {
+        self.print_styled(x, y, text, &TextStyle::default())
+    }
+
+    /// draw text with colours and attributes, aligned and cut off or wrapped inside a width
+    ///
+    /// wide characters take up two columns, and characters without a width are skipped.
+    /// returns the number of rows drawn
+    fn print_styled(&mut self, x: u32, y: u32, text: &str, style: &TextStyle) -> u32 {
+        let rows = style.layout(text);
+
+        for (row, (offset, line)) in rows.iter().enumerate() {
+            // rows and columns past the last coordinate are left out
+            let Some(y) = u32::try_from(row).ok().and_then(|row| y.checked_add(row)) else {
+                break;
+            };
+            let mut column = x.checked_add(*offset);
+            for c in line.chars() {
+                let width = char_width(c);
+                if width == 0 {
+                    continue;
+                }
+                let Some(x) = column else {
+                    break;
+                };
+                self.set(x, y, Cell::styled(c, style.style));
+                column = x.checked_add(width);
+            }
+        }
+
+        rows.len() as u32
+    }
+
+    /// draw a line `len` cells long, going right from the position
+    ///
+    /// lines join with lines and borders already drawn where they cross or meet
+    fn hline(&mut self, x: u32, y: u32, len: u32, style: BorderStyle) {
+        let len = fit(x, len);
+        let weight = Some(style.weight());
+        for i in 0..len {
+            let left = weight.filter(|_| i != 0);
+            let right = weight.filter(|_| i + 1 != len || len == 1);
+            stroke(self, x + i, y, [None, right, None, left], style);
+        }
+    }
+
+    /// draw a line `len` cells long, going down from the position
+    ///
+    /// lines join with lines and borders already drawn where they cross or meet
+    fn vline(&mut self, x: u32, y: u32, len: u32, style: BorderStyle) {
+        let len = fit(y, len);
+        let weight = Some(style.weight());
+        for i in 0..len {
+            let up = weight.filter(|_| i != 0);
+            let down = weight.filter(|_| i + 1 != len || len == 1);
+            stroke(self, x, y + i, [up, None, down, None], style);
+        }
+    }
+
+    /// draw the border of a rectangle, with its top left corner at the position
+    ///
+    /// borders join with lines and borders already drawn where they cross or meet
+    fn rect(&mut self, x: u32, y: u32, width: u32, height: u32, style: BorderStyle) {
+        let (width, height) = (fit(x, width), fit(y, height));
+        match (width, height) {
+            (0, _) | (_, 0) => return,
+            (_, 1) => return self.hline(x, y, width, style),
+            (1, _) => return self.vline(x, y, height, style),
+            _ => {}
+        }
+
+        let (right, bottom) = (x + (width - 1), y + (height - 1));
+        let w = Some(style.weight());
+        for x in x + 1..right {
+            stroke(self, x, y, [None, w, None, w], style);
+            stroke(self, x, bottom, [None, w, None, w], style);
+        }
+        for y in y + 1..bottom {
+            stroke(self, x, y, [w, None, w, None], style);
+            stroke(self, right, y, [w, None, w, None], style);
+        }
+        stroke(self, x, y, [None, w, w, None], style);
+        stroke(self, right, y, [None, None, w, w], style);
+        stroke(self, x, bottom, [w, w, None, None], style);
+        stroke(self, right, bottom, [w, None, None, w], style);
+    }
+
+    /// fill a rectangle with copies of a cell, such as a space with a background colour
+    fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, cell: Cell) {
+        for row in 0..fit(y, height) {
+            for column in 0..fit(x, width) {
+                self.set(x + column, y + row, cell);
+            }
+        }
+    }
+}
+
+/// cut a length starting at the position down to what fits before the last coordinate
+fn fit(pos: u32, len: u32) -> u32 {
+    len.min((u32::MAX - pos).saturating_add(1))
+}
+
+/// draw part of a line, joining with whatever is already drawn there
+fn stroke<S: Surface + ?Sized>(surface: &mut S, x: u32, y: u32, arms: Arms, style: BorderStyle) {
+    let existing = surface.get(x, y).map(|cell| cell.c);
+    surface.set(x, y, Cell::new(style.junction(arms, existing)));
+}
@@ -0,0 +1,164 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::bindings::Style;
+
+/// where lines are placed inside the width of a text
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    /// rounded to the left if the line does not fit exactly
+    Centre,
+    Right,
+}
+
+/// what happens to lines longer than the width of a text
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Overflow {
+    /// cut off at the width
+    #[default]
+    Clip,
+    /// cut off, with the last column replaced by `…`
+    Ellipsis,
+    /// continued on the next row, breaking between words where possible
+    Wrap,
+}
+
+/// how `Surface::print_styled` lays out and styles text
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TextStyle {
+    /// colours and attributes of each character
+    pub style: Style,
+    /// columns the text is aligned and cut off in, `None` never cuts off
+    pub width: Option<u32>,
+    pub align: Align,
+    pub overflow: Overflow,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            width: None,
+            align: Align::Left,
+            overflow: Overflow::Clip,
+        }
+    }
+}
+
+impl TextStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// alignment only has an effect with a width
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// overflow only has an effect with a width
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// split the text into rows, each with the column it starts at relative to the text
+    pub(crate) fn layout(&self, text: &str) -> Vec<(u32, String)> {
+        let Some(width) = self.width else {
+            return text.lines().map(|line| (0, line.to_string())).collect();
+        };
+        if width == 0 {
+            return Vec::new();
+        }
+
+        let lines = text.lines().flat_map(|line| match self.overflow {
+            Overflow::Clip => vec![clip(line, width)],
+            Overflow::Ellipsis if text_width(line) > width => {
+                let mut clipped = clip(line, width - 1);
+                clipped.push('…');
+                vec![clipped]
+            }
+            Overflow::Ellipsis => vec![line.to_string()],
+            Overflow::Wrap => wrap(line, width),
+        });
+
+        lines
+            .map(|line| {
+                let space = width.saturating_sub(text_width(&line));
+                let offset = match self.align {
+                    Align::Left => 0,
+                    Align::Centre => space / 2,
+                    Align::Right => space,
+                };
+                (offset, line)
+            })
+            .collect()
+    }
+}
+
+/// columns the text takes up on a terminal, wide characters such as CJK and emoji take up two
+///
+/// control characters take up none, as they are not drawn
+pub fn text_width(text: &str) -> u32 {
+    text.chars().map(char_width).sum()
+}
+
+/// columns a single character takes up on a terminal
+pub fn char_width(c: char) -> u32 {
+    c.width().unwrap_or(0) as u32
+}
+
+/// the longest start of the line which fits in the width
+fn clip(line: &str, width: u32) -> String {
+    let mut used = 0;
+    line.chars()
+        .take_while(|c| {
+            used += char_width(*c);
+            used <= width
+        })
+        .collect()
+}
+
+/// break the line between words, words longer than the width are broken wherever they have to be
+fn wrap(line: &str, width: u32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut used = 0;
+
+    for (i, word) in line.split(' ').enumerate() {
+        // the space between words is dropped when breaking there
+        if i != 0 && used != 0 {
+            if used + 1 + text_width(word) <= width {
+                current.push(' ');
+                used += 1;
+            } else {
+                lines.push(std::mem::take(&mut current));
+                used = 0;
+            }
+        }
+
+        for c in word.chars() {
+            let c_width = char_width(c);
+            if used + c_width > width && used != 0 {
+                lines.push(std::mem::take(&mut current));
+                used = 0;
+            }
+            current.push(c);
+            used += c_width;
+        }
+    }
+
+    lines.push(current);
+    lines
+}
@@ -1,3 +1,10 @@
 #![allow(clippy::module_inception)]
 pub mod bindings;
 pub mod client;
+#[cfg(feature = "proxy")]
+pub mod proxy;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod util;
@@ -0,0 +1,51 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bindings::{Request, Response, ResponseContent};
+
+/// a single line of the proxy log
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct LogEntry {
+    /// milliseconds since the unix epoch
+    pub timestamp: u64,
+    /// which component connection the message belongs to, numbered in order from 1
+    pub connection: u32,
+    #[serde(flatten)]
+    pub message: Message,
+}
+
+/// a message passing through the proxy
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "kind", content = "message", rename_all = "lowercase")]
+pub enum Message {
+    /// sent from a component to the canvas, as the component sent it
+    Request(Request),
+    /// sent from the canvas in reply to a request
+    Response(Response),
+    /// sent from the canvas without being asked
+    Event(Response),
+}
+
+impl LogEntry {
+    /// an entry timestamped now
+    pub fn now(connection: u32, message: Message) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_millis() as u64)
+                .unwrap_or_default(),
+            connection,
+            message,
+        }
+    }
+}
+
+impl From<Response> for Message {
+    fn from(value: Response) -> Self {
+        match value.content {
+            ResponseContent::Event { .. } => Self::Event(value),
+            _ => Self::Response(value),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! a proxy which sits between components and the canvas, logging all traffic as json lines
+
+mod proxy;
+pub use proxy::*;
+
+mod proxyconfig;
+pub use proxyconfig::*;
+
+mod logentry;
+pub use logentry::*;
@@ -0,0 +1,233 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{
+    io::AsyncWriteExt,
+    net::{UnixListener, UnixStream},
+};
+
+use crate::{
+    bindings::{Encoding, FrameReader, Request, RequestContent, Response},
+    client::ClientConfig,
+    util::{lock, AbortOnDrop},
+};
+
+use super::{LogEntry, Message, ProxyConfig};
+
+/// how long responses are still forwarded after a component stops sending requests,
+/// so the response to its drop request still reaches it
+pub const PROXY_LINGER: Duration = Duration::from_secs(1);
+
+type Log = Arc<Mutex<Box<dyn Write + Send>>>;
+
+/// forwards traffic between components and the canvas, logging every message on the way
+///
+/// set socket requests are rewritten to point at a listener socket of the proxy,
+/// which forwards responses and events on to the listener socket the component asked for
+pub struct Proxy {
+    config: Arc<ProxyConfig>,
+    listener: UnixListener,
+    log: Log,
+}
+
+/// a single component connection
+struct Connection {
+    id: u32,
+    config: Arc<ProxyConfig>,
+    log: Log,
+    /// ids of requests which were logged, so the responses to them are logged too
+    logged: Mutex<HashSet<u32>>,
+    /// where the component wants its responses, none until it sets its socket
+    listener_socket: Mutex<Option<PathBuf>>,
+    /// kept open between messages, so they arrive in order
+    listener_stream: tokio::sync::Mutex<Option<UnixStream>>,
+}
+
+impl Proxy {
+    /// bind to the request socket in the config, creating its parent dir if needed
+    ///
+    /// every message is written to `log` as a line of json
+    pub async fn bind(config: ProxyConfig, log: impl Write + Send + 'static) -> io::Result<Self> {
+        if let Some(parent) = config.request_socket.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        Ok(Self {
+            listener: UnixListener::bind(&config.request_socket)?,
+            config: Arc::new(config),
+            log: Arc::new(Mutex::new(Box::new(log))),
+        })
+    }
+
+    pub fn request_socket(&self) -> &Path {
+        &self.config.request_socket
+    }
+
+    /// forward connections until accepting fails, connections are closed when this returns
+    pub async fn run(&self) -> io::Result<()> {
+        let mut connections = Vec::new();
+        let mut count = 0;
+
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            connections.retain(|task: &AbortOnDrop| !task.0.is_finished());
+            count += 1;
+
+            let connection = Arc::new(Connection {
+                id: count,
+                config: self.config.clone(),
+                log: self.log.clone(),
+                logged: Mutex::default(),
+                listener_socket: Mutex::default(),
+                listener_stream: tokio::sync::Mutex::default(),
+            });
+            connections.push(AbortOnDrop(tokio::spawn(async move {
+                // a connection which fails only affects its own component
+                let _ = connection.forward(stream).await;
+            })));
+        }
+    }
+}
+
+impl Drop for Proxy {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.config.request_socket);
+    }
+}
+
+impl Connection {
+    async fn forward(self: Arc<Self>, component: UnixStream) -> io::Result<()> {
+        let upstream = UnixStream::connect(&self.config.upstream_socket).await?;
+
+        let listener_socket = ClientConfig::unique_listener_socket();
+        if let Some(parent) = listener_socket.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = UnixListener::bind(&listener_socket)?;
+        let _listener = RemoveOnDrop(listener_socket.clone());
+        let _responses = AbortOnDrop(tokio::spawn(self.clone().responses(listener)));
+
+        let res = self.requests(component, upstream, &listener_socket).await;
+        tokio::time::sleep(PROXY_LINGER).await;
+        res
+    }
+
+    /// forward requests from the component to the canvas
+    async fn requests(
+        &self,
+        component: UnixStream,
+        mut upstream: UnixStream,
+        listener_socket: &Path,
+    ) -> io::Result<()> {
+        let mut component = FrameReader::new(component);
+
+        while let Some(mut payload) = component.next_payload().await.map_err(io::Error::other)? {
+            let encoding = Encoding::detect(&payload);
+
+            // anything which cannot be read is forwarded untouched, for the canvas to reject
+            if let Ok(mut req) = encoding.decode::<Request>(&payload) {
+                self.log_request(&req);
+
+                if let RequestContent::SetSocket { path, .. } = &mut req.content {
+                    let path = std::mem::replace(path, listener_socket.to_path_buf());
+                    *lock(&self.listener_socket) = Some(path);
+                    *self.listener_stream.lock().await = None;
+                    payload = encoding.encode(&req).map_err(io::Error::other)?;
+                }
+            }
+
+            write_frame(&mut upstream, &payload).await?;
+        }
+
+        Ok(())
+    }
+
+    /// accept connections from the canvas to the listener socket of the proxy
+    async fn responses(self: Arc<Self>, listener: UnixListener) {
+        let mut connections = Vec::new();
+
+        while let Ok((stream, _)) = listener.accept().await {
+            connections.retain(|task: &AbortOnDrop| !task.0.is_finished());
+            let connection = self.clone();
+            connections.push(AbortOnDrop(tokio::spawn(async move {
+                let mut canvas = FrameReader::new(stream);
+                while let Ok(Some(payload)) = canvas.next_payload().await {
+                    if let Ok(res) = Encoding::detect(&payload).decode::<Response>(&payload) {
+                        connection.log_response(res);
+                    }
+                    // the component may be gone, which the canvas will notice by itself
+                    let _ = connection.send(&payload).await;
+                }
+            })));
+        }
+    }
+
+    /// forward a response to the listener socket of the component
+    async fn send(&self, payload: &[u8]) -> io::Result<()> {
+        let path = lock(&self.listener_socket).clone().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotConnected, "listener socket not set")
+        })?;
+
+        let mut stream = self.listener_stream.lock().await;
+        if let Some(stream) = stream.as_mut() {
+            if write_frame(stream, payload).await.is_ok() {
+                return Ok(());
+            }
+        }
+
+        // the component may have closed the connection since the last message
+        let mut connected = UnixStream::connect(path).await?;
+        write_frame(&mut connected, payload).await?;
+        *stream = Some(connected);
+        Ok(())
+    }
+
+    fn log_request(&self, req: &Request) {
+        if self.config.logs(&req.content) {
+            lock(&self.logged).insert(req.id());
+            self.write(Message::Request(req.clone()));
+        }
+    }
+
+    fn log_response(&self, res: Response) {
+        let message = Message::from(res);
+        if let Message::Response(res) = &message {
+            let requested = res.request.is_some_and(|id| lock(&self.logged).remove(&id));
+            if self.config.filter.is_some() && !requested {
+                return;
+            }
+        }
+        self.write(message);
+    }
+
+    fn write(&self, message: Message) {
+        let Ok(line) = serde_json::to_string(&LogEntry::now(self.id, message)) else {
+            return;
+        };
+
+        // a broken log should not break the components being debugged
+        let mut log = lock(&self.log);
+        let _ = writeln!(log, "{line}").and_then(|_| log.flush());
+    }
+}
+
+/// write a frame header followed by the payload, as it was read
+async fn write_frame(stream: &mut UnixStream, payload: &[u8]) -> io::Result<()> {
+    let mut frame = (payload.len() as u32).to_be_bytes().to_vec();
+    frame.extend_from_slice(payload);
+    stream.write_all(&frame).await
+}
+
+/// the listener socket of a connection is removed when it ends
+struct RemoveOnDrop(PathBuf);
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use crate::bindings::RequestContent;
+
+/// where the proxy listens, where it forwards to, and what it logs
+#[derive(Clone, Debug)]
+pub struct ProxyConfig {
+    /// components connect to this instead of the canvas
+    pub request_socket: PathBuf,
+    /// request socket of the real canvas
+    pub upstream_socket: PathBuf,
+    /// only log requests of these types, and the responses to them, `None` logs everything
+    ///
+    /// types are named as they are tagged on the wire, such as `"render"` or `"set socket"`,
+    /// events are always logged
+    pub filter: Option<Vec<String>>,
+}
+
+impl ProxyConfig {
+    pub fn new(request_socket: impl Into<PathBuf>, upstream_socket: impl Into<PathBuf>) -> Self {
+        Self {
+            request_socket: request_socket.into(),
+            upstream_socket: upstream_socket.into(),
+            filter: None,
+        }
+    }
+
+    /// only log requests of these types
+    pub fn with_filter<S: Into<String>>(mut self, kinds: impl IntoIterator<Item = S>) -> Self {
+        self.filter = Some(kinds.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// whether requests like this are logged
+    pub fn logs(&self, content: &RequestContent) -> bool {
+        match &self.filter {
+            // the tags are not consistently cased, so neither is the filter
+            Some(kinds) => kinds
+                .iter()
+                .any(|kind| kind.eq_ignore_ascii_case(content.kind())),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::Notify;
+
+use crate::util::lock;
+
+/// tracks which events have been confirmed, and with which pass value
+///
+/// event ids are unique across the whole server,
+/// so confirmations arriving on any connection are matched up
+#[derive(Clone, Default)]
+pub struct Confirmations(Arc<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    /// none until confirmed
+    events: Mutex<HashMap<u32, Option<bool>>>,
+    confirmed: Notify,
+}
+
+impl Confirmations {
+    /// start waiting for an event to be confirmed
+    pub fn expect(&self, id: u32) {
+        lock(&self.0.events).entry(id).or_insert(None);
+    }
+
+    /// record a confirmation, called for every confirm recieve request
+    pub fn confirm(&self, id: u32, pass: bool) {
+        lock(&self.0.events).insert(id, Some(pass));
+        self.0.confirmed.notify_waiters();
+    }
+
+    /// pass value of the event, none if it has not been confirmed yet
+    pub fn get(&self, id: u32) -> Option<bool> {
+        lock(&self.0.events).get(&id).copied().flatten()
+    }
+
+    /// ids of all events sent but not confirmed yet, in no particular order
+    pub fn pending(&self) -> Vec<u32> {
+        lock(&self.0.events)
+            .iter()
+            .filter(|(_, pass)| pass.is_none())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// wait for the event to be confirmed, returns its pass value
+    pub async fn wait(&self, id: u32) -> bool {
+        loop {
+            let notified = self.0.confirmed.notified();
+            tokio::pin!(notified);
+            // registered before checking, so a confirm in between is not missed
+            notified.as_mut().enable();
+
+            if let Some(pass) = self.get(id) {
+                return pass;
+            }
+            notified.await;
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use std::io;
+
+use crate::bindings::{Request, ResponseContent};
+
+use super::Peer;
+
+/// a request received by the server, along with the client that sent it
+pub struct Incoming {
+    pub request: Request,
+    pub peer: Peer,
+}
+
+impl Incoming {
+    /// respond to the request, the response carries the id of the request
+    pub async fn respond(&self, content: ResponseContent) -> io::Result<()> {
+        self.peer.respond(&self.request, content).await
+    }
+}
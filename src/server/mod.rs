@@ -0,0 +1,13 @@
+//! the server's half of the protocol, for writing canvases, proxies and test doubles
+
+mod server;
+pub use server::*;
+
+mod incoming;
+pub use incoming::*;
+
+mod peer;
+pub use peer::*;
+
+mod confirmations;
+pub use confirmations::*;
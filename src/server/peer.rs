@@ -0,0 +1,120 @@
+use std::{
+    io,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use tokio::{io::AsyncWriteExt, net::UnixStream};
+
+use crate::{
+    bindings::{
+        encode_frame_with, Encoding, EventVariant, Request, Response, ResponseContent,
+        ResponseSuccess,
+    },
+    util::lock,
+};
+
+use super::Confirmations;
+
+/// the client on the other end of a request connection,
+/// responses and events are sent to the listener socket it set
+///
+/// a client which reconnects without setting its socket again appears as a new peer without a listener,
+/// `Client` always sets it again first
+#[derive(Clone)]
+pub struct Peer(Arc<Inner>);
+
+struct Inner {
+    listener_socket: Mutex<Option<PathBuf>>,
+    encoding: Mutex<Encoding>,
+    /// kept open between messages, so they arrive in order
+    connection: tokio::sync::Mutex<Option<UnixStream>>,
+    confirmations: Confirmations,
+    event_ids: Arc<AtomicU32>,
+}
+
+impl Peer {
+    pub(crate) fn new(confirmations: Confirmations, event_ids: Arc<AtomicU32>) -> Self {
+        Self(Arc::new(Inner {
+            listener_socket: Mutex::default(),
+            encoding: Mutex::default(),
+            connection: tokio::sync::Mutex::default(),
+            confirmations,
+            event_ids,
+        }))
+    }
+
+    /// none until the client has sent a set socket request
+    pub fn listener_socket(&self) -> Option<PathBuf> {
+        lock(&self.0.listener_socket).clone()
+    }
+
+    pub(crate) async fn set_listener_socket(&self, path: PathBuf) {
+        *lock(&self.0.listener_socket) = Some(path);
+        *self.0.connection.lock().await = None;
+    }
+
+    /// respond to a request from this client
+    ///
+    /// responding to a set socket request with listener set switches to the agreed encoding afterwards
+    pub async fn respond(&self, req: &Request, content: ResponseContent) -> io::Result<()> {
+        let encoding = match &content {
+            ResponseContent::Success {
+                content: ResponseSuccess::ListenerSet { encoding, .. },
+            } => Some(*encoding),
+            _ => None,
+        };
+
+        self.send(&Response {
+            content,
+            id: 0,
+            request: Some(req.id()),
+        })
+        .await?;
+
+        if let Some(encoding) = encoding {
+            *lock(&self.0.encoding) = encoding;
+        }
+        Ok(())
+    }
+
+    /// send an event to the client, returns the id it will be confirmed with
+    pub async fn send_event(&self, event: EventVariant) -> io::Result<u32> {
+        let id = self.0.event_ids.fetch_add(1, Ordering::Relaxed) + 1;
+        self.0.confirmations.expect(id);
+        self.send(&Response {
+            content: ResponseContent::Event { content: event },
+            id,
+            request: None,
+        })
+        .await?;
+        Ok(id)
+    }
+
+    /// write a response to the listener socket of the client
+    ///
+    /// errors if the client has not set its listener socket yet
+    pub async fn send(&self, res: &Response) -> io::Result<()> {
+        let encoding = *lock(&self.0.encoding);
+        let frame = encode_frame_with(res, encoding).map_err(io::Error::other)?;
+        let path = self.listener_socket().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotConnected, "listener socket not set")
+        })?;
+
+        let mut connection = self.0.connection.lock().await;
+        if let Some(stream) = connection.as_mut() {
+            if stream.write_all(&frame).await.is_ok() {
+                return Ok(());
+            }
+        }
+
+        // the client may have closed the connection since the last message
+        let mut stream = UnixStream::connect(path).await?;
+        stream.write_all(&frame).await?;
+        *connection = Some(stream);
+        Ok(())
+    }
+}
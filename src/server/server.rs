@@ -0,0 +1,119 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicU32, Arc},
+};
+
+use tokio::{
+    net::{UnixListener, UnixStream},
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::{
+    bindings::{FrameError, FrameReader, Request, RequestContent},
+    util::AbortOnDrop,
+};
+
+use super::{Confirmations, Incoming, Peer};
+
+/// listens on the request socket, and yields every request from every client in the order they arrive
+///
+/// listener sockets and event confirmations are tracked before the requests are yielded,
+/// set socket requests still have to be responded to
+pub struct Server {
+    request_socket: PathBuf,
+    incoming: UnboundedReceiver<Incoming>,
+    confirmations: Confirmations,
+    accept_handle: JoinHandle<()>,
+}
+
+impl Server {
+    /// bind to the request socket at `path`, creating its parent dir if needed
+    pub async fn bind(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let request_socket = path.into();
+        if let Some(parent) = request_socket.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = UnixListener::bind(&request_socket)?;
+        let (incoming_send, incoming) = mpsc::unbounded_channel();
+        let confirmations = Confirmations::default();
+
+        let accept_handle = {
+            let confirmations = confirmations.clone();
+            let event_ids = Arc::new(AtomicU32::new(0));
+            tokio::spawn(async move {
+                let mut connections = Vec::new();
+                while let Ok((stream, _)) = listener.accept().await {
+                    connections.retain(|task: &AbortOnDrop| !task.0.is_finished());
+                    let peer = Peer::new(confirmations.clone(), event_ids.clone());
+                    connections.push(AbortOnDrop(tokio::spawn(Self::serve(
+                        FrameReader::new(stream),
+                        peer,
+                        confirmations.clone(),
+                        incoming_send.clone(),
+                    ))));
+                }
+            })
+        };
+
+        Ok(Self {
+            request_socket,
+            incoming,
+            confirmations,
+            accept_handle,
+        })
+    }
+
+    pub fn request_socket(&self) -> &Path {
+        &self.request_socket
+    }
+
+    /// confirmations of events sent to any client
+    pub fn confirmations(&self) -> &Confirmations {
+        &self.confirmations
+    }
+
+    /// wait for the next request from any client
+    pub async fn accept(&mut self) -> Option<Incoming> {
+        self.incoming.recv().await
+    }
+
+    /// handle all requests from a single connection, in order
+    async fn serve(
+        mut stream: FrameReader<UnixStream>,
+        peer: Peer,
+        confirmations: Confirmations,
+        incoming: UnboundedSender<Incoming>,
+    ) {
+        loop {
+            let request: Request = match stream.next().await {
+                Ok(Some(req)) => req,
+                // the frame was read in full, so the next one is still intact
+                Err(FrameError::Malformed(_)) => continue,
+                Ok(None) | Err(_) => break,
+            };
+
+            match &request.content {
+                RequestContent::ConfirmRecieve { id, pass } => confirmations.confirm(*id, *pass),
+                RequestContent::SetSocket { path, .. } => {
+                    peer.set_listener_socket(path.clone()).await
+                }
+                _ => {}
+            }
+
+            let peer = peer.clone();
+            if incoming.send(Incoming { request, peer }).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        // aborting the accept loop drops every connection task with it
+        self.accept_handle.abort();
+        let _ = std::fs::remove_file(&self.request_socket);
+    }
+}
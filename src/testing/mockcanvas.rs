@@ -0,0 +1,284 @@
+use std::{
+    collections::VecDeque,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use tokio::{
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::{
+    bindings::{
+        Capability, Discriminator, Encoding, EventVariant, Request, RequestContent,
+        ResponseContent, ResponseSuccess, PROTOCOL_VERSION,
+    },
+    client::{ClientConfig, DEFAULT_REQUEST_TIMEOUT},
+    server::{Confirmations, Incoming, Peer, Server},
+    util::lock,
+};
+
+use super::{Reply, VirtualScreen};
+
+/// how long the mock waits for the client before giving up
+pub const MOCK_WAIT: Duration = Duration::from_secs(5);
+
+/// size of the screen unless set otherwise
+pub const MOCK_SCREEN_SIZE: (u32, u32) = (80, 24);
+
+type Responder = Box<dyn Fn(&Request) -> Reply + Send + Sync>;
+
+/// an in process canvas which records every request it gets
+///
+/// requests are answered with scripted responses first, then by the responder if one is set,
+/// and otherwise with the response a working canvas would give
+pub struct MockCanvas {
+    request_socket: PathBuf,
+    shared: Arc<Shared>,
+    received: UnboundedReceiver<Request>,
+    confirmations: Confirmations,
+    serve_handle: JoinHandle<()>,
+}
+
+struct Shared {
+    /// every request received so far, in order
+    requests: Mutex<Vec<Request>>,
+    received: UnboundedSender<Request>,
+    script: Mutex<VecDeque<Reply>>,
+    responder: Mutex<Option<Responder>>,
+    /// the client which most recently set its listener socket, events are sent to it
+    peer: Mutex<Option<Peer>>,
+    spawned: AtomicU32,
+    protocol: Mutex<Option<u32>>,
+    capabilities: Mutex<Vec<Capability>>,
+    /// every render request is applied to this
+    screen: Mutex<VirtualScreen>,
+}
+
+impl MockCanvas {
+    /// bind to a unique request socket in the temp dir
+    pub async fn new() -> io::Result<Self> {
+        static COUNT: AtomicU32 = AtomicU32::new(0);
+
+        Self::bind(std::env::temp_dir().join("ccanvas").join(format!(
+            "mock-{}-{}.sock",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        )))
+        .await
+    }
+
+    /// bind to the request socket at `path`, creating its parent dir if needed
+    pub async fn bind(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let mut server = Server::bind(path).await?;
+        let request_socket = server.request_socket().to_path_buf();
+        let confirmations = server.confirmations().clone();
+        let (received_send, received) = mpsc::unbounded_channel();
+
+        let shared = Arc::new(Shared {
+            requests: Mutex::default(),
+            received: received_send,
+            script: Mutex::default(),
+            responder: Mutex::default(),
+            peer: Mutex::default(),
+            spawned: AtomicU32::new(1),
+            protocol: Mutex::new(Some(PROTOCOL_VERSION)),
+            capabilities: Mutex::default(),
+            screen: Mutex::new(VirtualScreen::new(MOCK_SCREEN_SIZE.0, MOCK_SCREEN_SIZE.1)),
+        });
+
+        let serve_handle = {
+            let shared = shared.clone();
+            tokio::spawn(async move {
+                while let Some(incoming) = server.accept().await {
+                    shared.handle(incoming).await;
+                }
+            })
+        };
+
+        Ok(Self {
+            request_socket,
+            shared,
+            received,
+            confirmations,
+            serve_handle,
+        })
+    }
+
+    /// protocol version sent when the listener is set, none acts like a canvas from before versioning
+    pub fn with_protocol(self, protocol: Option<u32>) -> Self {
+        *lock(&self.shared.protocol) = protocol;
+        self
+    }
+
+    /// capabilities sent when the listener is set
+    pub fn with_capabilities(self, capabilities: Vec<Capability>) -> Self {
+        *lock(&self.shared.capabilities) = capabilities;
+        self
+    }
+
+    /// start with a blank screen of a different size
+    pub fn with_screen_size(self, width: u32, height: u32) -> Self {
+        *lock(&self.shared.screen) = VirtualScreen::new(width, height);
+        self
+    }
+
+    /// what the screen looks like after all render requests so far
+    pub fn screen(&self) -> VirtualScreen {
+        lock(&self.shared.screen).clone()
+    }
+
+    pub fn request_socket(&self) -> &Path {
+        &self.request_socket
+    }
+
+    /// a client config which connects to this canvas, with a unique listener socket
+    pub fn config(&self) -> ClientConfig {
+        ClientConfig {
+            listener_socket: ClientConfig::unique_listener_socket(),
+            request_socket: self.request_socket.clone(),
+            request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+        }
+    }
+
+    /// answer the next request with `reply`, replies are used in the order they are scripted
+    ///
+    /// set socket and confirm recieve requests do not use up scripted replies
+    pub fn script(&self, reply: impl Into<Reply>) {
+        lock(&self.shared.script).push_back(reply.into());
+    }
+
+    /// decide how to answer every request which has no scripted reply
+    pub fn respond_with<F: Fn(&Request) -> Reply + Send + Sync + 'static>(&self, f: F) {
+        *lock(&self.shared.responder) = Some(Box::new(f));
+    }
+
+    /// all requests received so far, in order
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.shared.requests).clone()
+    }
+
+    /// wait for the next request which has not been taken yet,
+    /// none if it does not arrive within `MOCK_WAIT`
+    pub async fn next_request(&mut self) -> Option<Request> {
+        tokio::time::timeout(MOCK_WAIT, self.received.recv())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// send an event to the client, returns the id it has to be confirmed with
+    ///
+    /// errors if the client has not set its listener socket yet
+    pub async fn send_event(&self, event: EventVariant) -> io::Result<u32> {
+        let peer = lock(&self.shared.peer)
+            .clone()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "listener not set"))?;
+        peer.send_event(event).await
+    }
+
+    /// wait for the event to be confirmed, returns its pass value
+    ///
+    /// none if it is not confirmed within `MOCK_WAIT`
+    pub async fn confirmation(&self, event: u32) -> Option<bool> {
+        tokio::time::timeout(MOCK_WAIT, self.confirmations.wait(event))
+            .await
+            .ok()
+    }
+
+    /// panics unless the event is confirmed with the expected pass value
+    pub async fn assert_confirmed(&self, event: u32, pass: bool) {
+        match self.confirmation(event).await {
+            Some(confirmed) => assert_eq!(
+                confirmed, pass,
+                "event {event} was confirmed with pass = {confirmed}, expected pass = {pass}"
+            ),
+            None => panic!("event {event} was not confirmed within {MOCK_WAIT:?}"),
+        }
+    }
+}
+
+impl Drop for MockCanvas {
+    fn drop(&mut self) {
+        // the server is dropped along with the task, and removes the socket
+        self.serve_handle.abort();
+    }
+}
+
+impl Shared {
+    /// record a request and reply to it
+    async fn handle(&self, incoming: Incoming) {
+        let req = &incoming.request;
+        lock(&self.requests).push(req.clone());
+        lock(&self.screen).apply_request(req);
+        let _ = self.received.send(req.clone());
+
+        let reply = match &req.content {
+            // already tracked by the server
+            RequestContent::ConfirmRecieve { .. } => return,
+            RequestContent::SetSocket { .. } => {
+                *lock(&self.peer) = Some(incoming.peer.clone());
+                self.respond(req)
+            }
+            _ => {
+                let scripted = lock(&self.script).pop_front();
+                scripted.unwrap_or_else(|| self.respond(req))
+            }
+        };
+
+        let content = match reply {
+            Reply::Default => self.default_response(&req.content),
+            Reply::Respond(content) => Some(content),
+            Reply::Ignore => None,
+        };
+
+        if let Some(content) = content {
+            // the client may already be gone, which the test will notice by itself
+            let _ = incoming.respond(content).await;
+        }
+    }
+
+    fn respond(&self, req: &Request) -> Reply {
+        match &*lock(&self.responder) {
+            Some(responder) => responder(req),
+            None => Reply::Default,
+        }
+    }
+
+    /// what a working canvas would respond with, none for requests without responses
+    fn default_response(&self, content: &RequestContent) -> Option<ResponseContent> {
+        let content = match content {
+            RequestContent::ConfirmRecieve { .. } => return None,
+            RequestContent::SetSocket { .. } => ResponseSuccess::ListenerSet {
+                encoding: Encoding::Json,
+                protocol: *lock(&self.protocol),
+                capabilities: lock(&self.capabilities).clone(),
+            },
+            RequestContent::Subscribe { .. } => ResponseSuccess::SubscribeAdded,
+            RequestContent::Unsubscribe { .. } => ResponseSuccess::SubscribeRemoved,
+            RequestContent::Drop { .. } => ResponseSuccess::Dropped,
+            RequestContent::Render { .. } => ResponseSuccess::Rendered,
+            RequestContent::Spawn { .. } => ResponseSuccess::Spawned {
+                discrim: self.next_discrim(),
+            },
+            RequestContent::NewSpace { .. } => ResponseSuccess::SpaceCreated {
+                discrim: self.next_discrim(),
+            },
+            RequestContent::Message { .. } => ResponseSuccess::MessageDelivered,
+            RequestContent::FocusAt => ResponseSuccess::FocusChanged,
+        };
+
+        Some(ResponseContent::Success { content })
+    }
+
+    /// spawned components and spaces are children of the master, numbered in order from 2
+    fn next_discrim(&self) -> Discriminator {
+        Discriminator::new(vec![1, self.spawned.fetch_add(1, Ordering::Relaxed) + 1])
+    }
+}
@@ -0,0 +1,10 @@
+//! helpers for testing components without a running canvas or terminal
+
+mod mockcanvas;
+pub use mockcanvas::*;
+
+mod reply;
+pub use reply::*;
+
+mod virtualscreen;
+pub use virtualscreen::*;
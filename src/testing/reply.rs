@@ -0,0 +1,18 @@
+use crate::bindings::ResponseContent;
+
+/// how the mock canvas answers a request
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reply {
+    /// the response a working canvas would give
+    Default,
+    /// respond with this instead
+    Respond(ResponseContent),
+    /// never respond, as if the canvas got stuck
+    Ignore,
+}
+
+impl From<ResponseContent> for Reply {
+    fn from(value: ResponseContent) -> Self {
+        Self::Respond(value)
+    }
+}
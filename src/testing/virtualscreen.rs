@@ -0,0 +1,240 @@
+use std::fmt::{Display, Write};
+
+use crate::{
+    bindings::{
+        Attributes, Cell, Colour, CursorStyle, RenderRequest, Request, RequestContent, Style,
+    },
+    client::char_width,
+};
+
+/// an in memory terminal which render requests are applied to, for snapshot tests
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VirtualScreen {
+    width: u32,
+    height: u32,
+    /// row by row
+    cells: Vec<Cell>,
+    /// cells covered by the right half of a wide character to their left, row by row
+    continuations: Vec<bool>,
+    cursor_visible: bool,
+    cursor_style: Option<CursorStyle>,
+}
+
+impl VirtualScreen {
+    /// a blank screen
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); width as usize * height as usize],
+            continuations: vec![false; width as usize * height as usize],
+            cursor_visible: true,
+            cursor_style: None,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// none if the position is off screen
+    ///
+    /// the right half of a wide character is a blank cell, see `is_continuation`
+    pub fn cell(&self, x: u32, y: u32) -> Option<&Cell> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    /// true if the cell is covered by a wide character to its left
+    pub fn is_continuation(&self, x: u32, y: u32) -> bool {
+        self.index(x, y).is_some_and(|i| self.continuations[i])
+    }
+
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// none until a cursor style has been set
+    pub fn cursor_style(&self) -> Option<CursorStyle> {
+        self.cursor_style
+    }
+
+    /// apply a render request, characters off screen are ignored like a terminal would
+    pub fn apply(&mut self, req: &RenderRequest) {
+        match req {
+            RenderRequest::SetChar { x, y, c } => self.set(*x, *y, Cell::new(*c)),
+            RenderRequest::SetCharColoured { x, y, c, fg, bg } => {
+                self.set(*x, *y, Cell::coloured(*c, *fg, *bg))
+            }
+            RenderRequest::SetCharStyled { x, y, c, style } => {
+                self.set(*x, *y, Cell::styled(*c, *style))
+            }
+            RenderRequest::SetCursorStyle { style } => self.cursor_style = Some(*style),
+            RenderRequest::HideCursor => self.cursor_visible = false,
+            RenderRequest::ShowCursor => self.cursor_visible = true,
+            RenderRequest::RenderMultiple { tasks } => {
+                tasks.iter().for_each(|task| self.apply(task))
+            }
+            RenderRequest::Flush => {}
+        }
+    }
+
+    /// apply the render request inside of a request, other requests are ignored
+    pub fn apply_request(&mut self, req: &Request) {
+        if let RequestContent::Render { content, .. } = &req.content {
+            self.apply(content)
+        }
+    }
+
+    /// the characters on screen without colours, one line per row
+    ///
+    /// trailing spaces are trimmed from each line, so snapshots are easier to write
+    pub fn to_plain(&self) -> String {
+        self.rows()
+            .map(|row| {
+                row.map(|cell| cell.c)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// the screen with ansi colour and attribute codes, one line per row
+    ///
+    /// codes are only written when the style changes, and no line ends with a style still set
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                out.push('\n');
+            }
+
+            let mut current = Style::default();
+            for cell in row {
+                if cell.style() != current {
+                    out.push_str("\x1b[0");
+                    push_attributes(&mut out, cell.attributes);
+                    push_sgr(&mut out, cell.fg, false);
+                    push_sgr(&mut out, cell.bg, true);
+                    out.push('m');
+                    current = cell.style();
+                }
+                out.push(cell.c);
+            }
+
+            if current != Style::default() {
+                out.push_str("\x1b[0m");
+            }
+        }
+
+        out
+    }
+
+    /// the cells of each row, without the right halves of wide characters
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &Cell>> {
+        // chunks panics on 0, an empty screen has no rows anyways
+        let width = self.width.max(1) as usize;
+        self.cells
+            .chunks(width)
+            .zip(self.continuations.chunks(width))
+            .map(|(cells, continuations)| {
+                cells
+                    .iter()
+                    .zip(continuations)
+                    .filter(|(_, continuation)| !**continuation)
+                    .map(|(cell, _)| cell)
+            })
+    }
+
+    /// like a terminal, a wide character also covers the cell to its right,
+    /// and a wide character which is partly overwritten is erased
+    fn set(&mut self, x: u32, y: u32, cell: Cell) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+
+        self.clear(x, y);
+        if char_width(cell.c) == 2 {
+            if let Some(right) = self.index(x + 1, y) {
+                self.clear(x + 1, y);
+                self.cells[right] = Cell::styled(' ', cell.style());
+                self.continuations[right] = true;
+            }
+        }
+        self.cells[i] = cell;
+    }
+
+    /// blank both halves of the wide character at the position, if there is one
+    fn clear(&mut self, x: u32, y: u32) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+
+        if self.continuations[i] {
+            self.continuations[i] = false;
+            self.cells[i - 1].c = ' ';
+        } else if self.is_continuation(x + 1, y) {
+            self.continuations[i + 1] = false;
+        }
+    }
+
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y as usize * self.width as usize + x as usize)
+    }
+}
+
+impl Display for VirtualScreen {
+    /// same as `to_plain`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_plain())
+    }
+}
+
+/// append `;<code>` for each attribute
+fn push_attributes(out: &mut String, attributes: Attributes) {
+    let codes = [
+        (Attributes::BOLD, 1),
+        (Attributes::DIM, 2),
+        (Attributes::ITALIC, 3),
+        (Attributes::UNDERLINE, 4),
+        (Attributes::REVERSE, 7),
+        (Attributes::STRIKETHROUGH, 9),
+    ];
+    for (attribute, code) in codes {
+        if attributes.contains(attribute) {
+            let _ = write!(out, ";{code}");
+        }
+    }
+}
+
+/// append `;<code>` for the colour, reset colours need no code after the leading 0
+fn push_sgr(out: &mut String, colour: Colour, background: bool) {
+    let offset = if background { 10 } else { 0 };
+    let _ = match colour {
+        Colour::Reset => return,
+        Colour::Black => write!(out, ";{}", 30 + offset),
+        Colour::Red => write!(out, ";{}", 31 + offset),
+        Colour::Green => write!(out, ";{}", 32 + offset),
+        Colour::Yellow => write!(out, ";{}", 33 + offset),
+        Colour::Blue => write!(out, ";{}", 34 + offset),
+        Colour::Magenta => write!(out, ";{}", 35 + offset),
+        Colour::Cyan => write!(out, ";{}", 36 + offset),
+        Colour::White => write!(out, ";{}", 37 + offset),
+        Colour::LightBlack => write!(out, ";{}", 90 + offset),
+        Colour::LightRed => write!(out, ";{}", 91 + offset),
+        Colour::LightGreen => write!(out, ";{}", 92 + offset),
+        Colour::LightYellow => write!(out, ";{}", 93 + offset),
+        Colour::LightBlue => write!(out, ";{}", 94 + offset),
+        Colour::LightMagenta => write!(out, ";{}", 95 + offset),
+        Colour::LightCyan => write!(out, ";{}", 96 + offset),
+        Colour::LightWhite => write!(out, ";{}", 97 + offset),
+        Colour::Ansi { value } => write!(out, ";{};5;{value}", 38 + offset),
+        Colour::Rgb { red, green, blue } => write!(out, ";{};2;{red};{green};{blue}", 38 + offset),
+    };
+}
@@ -0,0 +1,10 @@
+use tokio::task::JoinHandle;
+
+/// connection tasks end along with the accept loop
+pub(crate) struct AbortOnDrop(pub(crate) JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -0,0 +1,6 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// lock a mutex which is never left half updated, so a poisoned lock is still usable
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
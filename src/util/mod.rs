@@ -0,0 +1,7 @@
+//! helpers shared by the client, server and proxy
+
+mod abortondrop;
+pub(crate) use abortondrop::*;
+
+mod lock;
+pub(crate) use lock::*;
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use libccanvas::{
+    bindings::{EventVariant, RenderRequest, RequestContent, ResponseContent, ResponseError},
+    client::{Client, ClientError},
+    testing::{MockCanvas, Reply},
+};
+
+#[tokio::test]
+async fn close_flushes_renders_and_drops_the_client() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let config = canvas.config();
+    let listener_socket = config.listener_socket.clone();
+    let mut client = Client::new(config).await.unwrap();
+    canvas.next_request().await.unwrap();
+    assert!(listener_socket.exists());
+
+    client.setchar(0, 0, 'a');
+    client.close().await.unwrap();
+
+    assert_eq!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Render {
+            content: RenderRequest::RenderMultiple {
+                tasks: vec![RenderRequest::setchar(0, 0, 'a')]
+            },
+            flush: true
+        }
+    );
+    assert_eq!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Drop { discrim: None }
+    );
+    assert!(!listener_socket.exists());
+}
+
+#[tokio::test]
+async fn close_drops_the_client_even_if_rendering_fails() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let config = canvas.config();
+    let listener_socket = config.listener_socket.clone();
+    let mut client = Client::new(config).await.unwrap();
+    canvas.next_request().await.unwrap();
+
+    canvas.script(ResponseContent::Error {
+        content: ResponseError::ComponentNotFound,
+    });
+    client.setchar(0, 0, 'a');
+
+    assert!(matches!(
+        client.close().await,
+        Err(ClientError::Response(ResponseError::ComponentNotFound))
+    ));
+    assert!(matches!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Render { .. }
+    ));
+    assert_eq!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Drop { discrim: None }
+    );
+    assert!(!listener_socket.exists());
+}
+
+#[tokio::test]
+async fn close_removes_the_socket_even_if_the_canvas_does_not_respond() {
+    let canvas = MockCanvas::new().await.unwrap();
+    canvas.script(Reply::Ignore);
+    let config = canvas
+        .config()
+        .with_request_timeout(Some(Duration::from_millis(50)));
+    let listener_socket = config.listener_socket.clone();
+    let client = Client::new(config).await.unwrap();
+
+    assert!(matches!(
+        client.close().await,
+        Err(ClientError::Timeout { .. })
+    ));
+    assert!(!listener_socket.exists());
+}
+
+#[tokio::test]
+async fn drop_queues_a_drop_request() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let config = canvas.config();
+    let listener_socket = config.listener_socket.clone();
+    let client = Client::new(config).await.unwrap();
+    canvas.next_request().await.unwrap();
+
+    drop(client);
+
+    assert_eq!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Drop { discrim: None }
+    );
+    assert!(!listener_socket.exists());
+}
+
+#[tokio::test]
+async fn close_ends_the_connections_to_the_listener() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+
+    canvas.send_event(EventVariant::Focused).await.unwrap();
+    client.recv().await.unwrap();
+    client.close().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(canvas.send_event(EventVariant::Focused).await.is_err());
+}
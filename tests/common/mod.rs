@@ -0,0 +1,40 @@
+// each test file only uses some of the helpers
+#![allow(dead_code)]
+
+use libccanvas::{
+    bindings::{RenderRequest, RequestContent},
+    client::Client,
+    testing::MockCanvas,
+};
+
+/// a client connected to the canvas
+pub async fn connect(canvas: &MockCanvas) -> Client {
+    Client::new(canvas.config()).await.unwrap()
+}
+
+/// draw on a fresh canvas of the given size and render, returns the screen as plain text
+pub async fn draw(width: u32, height: u32, f: impl FnOnce(&mut Client)) -> String {
+    let canvas = MockCanvas::new()
+        .await
+        .unwrap()
+        .with_screen_size(width, height);
+    let mut client = connect(&canvas).await;
+    f(&mut client);
+    client.renderall().await.unwrap();
+    canvas.screen().to_plain()
+}
+
+/// the render tasks of every render request the canvas received, in order
+pub fn renders(canvas: &MockCanvas) -> Vec<Vec<RenderRequest>> {
+    canvas
+        .requests()
+        .into_iter()
+        .filter_map(|req| match req.content {
+            RequestContent::Render {
+                content: RenderRequest::RenderMultiple { tasks },
+                ..
+            } => Some(tasks),
+            _ => None,
+        })
+        .collect()
+}
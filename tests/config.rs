@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use libccanvas::client::{
+    ClientConfig, LISTENER_SOCKET_ARG, LISTENER_SOCKET_ENV, REQUEST_SOCKET_ARG, REQUEST_SOCKET_ENV,
+};
+
+/// a config with known paths, so only the args change it
+fn config() -> ClientConfig {
+    ClientConfig {
+        listener_socket: PathBuf::from("listen.sock"),
+        request_socket: PathBuf::from("requests.sock"),
+        request_timeout: None,
+    }
+}
+
+#[test]
+fn args_take_the_next_value() {
+    let config = config().with_args([
+        LISTENER_SOCKET_ARG,
+        "/tmp/a.sock",
+        REQUEST_SOCKET_ARG,
+        "/tmp/b.sock",
+    ]);
+    assert_eq!(config.listener_socket, PathBuf::from("/tmp/a.sock"));
+    assert_eq!(config.request_socket, PathBuf::from("/tmp/b.sock"));
+}
+
+#[test]
+fn args_take_a_value_after_equals() {
+    let config = config().with_args([
+        format!("{LISTENER_SOCKET_ARG}=/tmp/a.sock"),
+        format!("{REQUEST_SOCKET_ARG}=/tmp/b=c.sock"),
+    ]);
+    assert_eq!(config.listener_socket, PathBuf::from("/tmp/a.sock"));
+    assert_eq!(config.request_socket, PathBuf::from("/tmp/b=c.sock"));
+}
+
+#[test]
+fn unrelated_args_are_ignored() {
+    let config = config().with_args([
+        "snake",
+        "--fast",
+        "--level=3",
+        REQUEST_SOCKET_ARG,
+        "/tmp/b.sock",
+        "--ccanvas-listener-socket-extra",
+        "/tmp/x.sock",
+    ]);
+    assert_eq!(config.listener_socket, PathBuf::from("listen.sock"));
+    assert_eq!(config.request_socket, PathBuf::from("/tmp/b.sock"));
+}
+
+#[test]
+fn args_without_a_value_change_nothing() {
+    let config = config().with_args([REQUEST_SOCKET_ARG, "/tmp/b.sock", LISTENER_SOCKET_ARG]);
+    assert_eq!(config.listener_socket, PathBuf::from("listen.sock"));
+    assert_eq!(config.request_socket, PathBuf::from("/tmp/b.sock"));
+}
+
+// the env is shared by every test in this file, so it is only changed in this one
+#[test]
+fn env_is_used_unless_args_override_it() {
+    std::env::remove_var(LISTENER_SOCKET_ENV);
+    std::env::remove_var(REQUEST_SOCKET_ENV);
+    let config = ClientConfig::from_env();
+    assert_eq!(config.request_socket, PathBuf::from("requests.sock"));
+    // a unique listener socket is generated for each config
+    assert!(config
+        .listener_socket
+        .parent()
+        .unwrap()
+        .ends_with("ccanvas"));
+    assert_ne!(
+        ClientConfig::from_env().listener_socket,
+        config.listener_socket
+    );
+
+    std::env::set_var(LISTENER_SOCKET_ENV, "/tmp/env-listen.sock");
+    std::env::set_var(REQUEST_SOCKET_ENV, "/tmp/env-requests.sock");
+    let config = ClientConfig::from_env();
+    assert_eq!(
+        config.listener_socket,
+        PathBuf::from("/tmp/env-listen.sock")
+    );
+    assert_eq!(
+        config.request_socket,
+        PathBuf::from("/tmp/env-requests.sock")
+    );
+
+    let config = ClientConfig::from_env().with_args([REQUEST_SOCKET_ARG, "/tmp/arg.sock"]);
+    assert_eq!(
+        config.listener_socket,
+        PathBuf::from("/tmp/env-listen.sock")
+    );
+    assert_eq!(config.request_socket, PathBuf::from("/tmp/arg.sock"));
+
+    std::env::remove_var(LISTENER_SOCKET_ENV);
+    std::env::remove_var(REQUEST_SOCKET_ENV);
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use libccanvas::{
+    client::{Client, ClientConfig, ClientError},
+    testing::MockCanvas,
+};
+
+/// a fresh directory for the sockets of a single test
+fn dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("libccanvas-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[tokio::test]
+async fn files_at_the_listener_path_are_not_removed() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let listener_socket = dir("bind").join("notes.txt");
+    std::fs::write(&listener_socket, "keep me").unwrap();
+    let config = ClientConfig {
+        listener_socket: listener_socket.clone(),
+        ..canvas.config()
+    };
+
+    match Client::new(config).await {
+        Err(ClientError::Bind { path, .. }) => assert_eq!(path, listener_socket),
+        Err(e) => panic!("expected a bind error, got {e}"),
+        Ok(_) => panic!("expected a bind error"),
+    }
+    assert_eq!(
+        std::fs::read_to_string(&listener_socket).unwrap(),
+        "keep me"
+    );
+}
+
+#[tokio::test]
+async fn nothing_listening_on_the_request_socket_is_a_connect_error() {
+    let dir = dir("connect");
+    let request_socket = dir.join("requests.sock");
+    let config = ClientConfig {
+        listener_socket: dir.join("listen.sock"),
+        request_socket: request_socket.clone(),
+        request_timeout: None,
+    };
+
+    match Client::new(config).await {
+        Err(ClientError::Connect { path, .. }) => assert_eq!(path, request_socket),
+        Err(e) => panic!("expected a connect error, got {e}"),
+        Ok(_) => panic!("expected a connect error"),
+    }
+}
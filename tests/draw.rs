@@ -0,0 +1,162 @@
+mod common;
+
+use libccanvas::{
+    bindings::{Cell, Colour},
+    client::{BorderStyle, Surface},
+    testing::MockCanvas,
+};
+
+#[tokio::test]
+async fn draws_borders_in_every_style() {
+    let cases = [
+        (BorderStyle::Single, "┌──┐\n│  │\n└──┘"),
+        (BorderStyle::Double, "╔══╗\n║  ║\n╚══╝"),
+        (BorderStyle::Rounded, "╭──╮\n│  │\n╰──╯"),
+        (BorderStyle::Heavy, "┏━━┓\n┃  ┃\n┗━━┛"),
+        (BorderStyle::Ascii, "+--+\n|  |\n+--+"),
+    ];
+
+    for (style, expected) in cases {
+        let screen = common::draw(8, 5, |client| client.rect(0, 0, 4, 3, style)).await;
+        assert_eq!(screen, format!("{expected}\n\n"), "{style:?}");
+    }
+}
+
+#[tokio::test]
+async fn draws_lines() {
+    let screen = common::draw(8, 5, |client| {
+        client.hline(0, 0, 3, BorderStyle::Single);
+        client.vline(4, 0, 2, BorderStyle::Heavy);
+        client.hline(0, 3, 1, BorderStyle::Double);
+    })
+    .await;
+    assert_eq!(screen, "─── ┃\n    ┃\n\n═\n");
+}
+
+#[tokio::test]
+async fn crossing_lines_join() {
+    let screen = common::draw(8, 5, |client| {
+        client.hline(0, 1, 5, BorderStyle::Single);
+        client.vline(2, 0, 3, BorderStyle::Single);
+        // ends which meet a line join it
+        client.vline(1, 1, 2, BorderStyle::Single);
+        client.vline(3, 0, 2, BorderStyle::Heavy);
+    })
+    .await;
+    assert_eq!(screen, "  │┃\n─┬┼┸─\n ││\n\n");
+}
+
+#[tokio::test]
+async fn dividers_join_borders() {
+    let screen = common::draw(8, 5, |client| {
+        client.rect(0, 0, 5, 4, BorderStyle::Rounded);
+        client.hline(0, 2, 5, BorderStyle::Single);
+        client.vline(2, 0, 4, BorderStyle::Double);
+    })
+    .await;
+    assert_eq!(screen, "╭─╥─╮\n│ ║ │\n├─╫─┤\n╰─╨─╯\n");
+}
+
+#[tokio::test]
+async fn mixes_without_a_character_use_the_newer_style() {
+    let screen = common::draw(8, 5, |client| {
+        client.hline(0, 0, 3, BorderStyle::Heavy);
+        client.vline(1, 0, 2, BorderStyle::Double);
+        client.hline(0, 3, 3, BorderStyle::Single);
+        client.vline(1, 2, 2, BorderStyle::Ascii);
+    })
+    .await;
+    assert_eq!(screen, "━╦━\n ║\n |\n─+─\n");
+}
+
+#[tokio::test]
+async fn fills_rectangles() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = common::connect(&canvas).await;
+    let cell = Cell::coloured(' ', Colour::Reset, Colour::Blue);
+    client.fill(1, 1, 2, 2, cell);
+
+    assert_eq!(client.frame().get(1, 1), Some(&cell));
+    assert_eq!(client.frame().get(2, 2), Some(&cell));
+    assert_eq!(client.frame().get(3, 2), None);
+    assert_eq!(client.frame().get(2, 3), None);
+}
+
+#[tokio::test]
+async fn translates_and_clips() {
+    let screen = common::draw(8, 5, |client| {
+        let mut region = client.region(2, 1, 4, 2);
+        assert_eq!(region.size(), Some((4, 2)));
+        assert_eq!(region.origin(), (2, 1));
+
+        region.print(0, 0, "abcdef\nghi\njkl");
+        region.setchar(4, 0, 'x');
+        assert_eq!(region.get(1, 1), Some(Cell::new('h')));
+        assert_eq!(region.get(3, 1), None);
+    })
+    .await;
+    assert_eq!(screen, "\n  abcd\n  ghi\n\n");
+}
+
+#[tokio::test]
+async fn regions_nest() {
+    let screen = common::draw(8, 5, |client| {
+        let mut outer = client.region(1, 1, 5, 3);
+        let mut inner = outer.region(2, 1, 10, 10);
+        // cut down to what is left of the outer region
+        assert_eq!(inner.size(), Some((3, 2)));
+        inner.print(0, 0, "12345\n67890\nabcde");
+    })
+    .await;
+    assert_eq!(screen, "\n\n   123\n   678\n");
+}
+
+#[tokio::test]
+async fn widgets_draw_in_local_coordinates() {
+    fn widget(surface: &mut impl Surface) {
+        let (width, height) = surface.size().unwrap();
+        surface.rect(0, 0, width, height, BorderStyle::Single);
+        surface.print(1, 1, "hi");
+    }
+
+    let screen = common::draw(8, 5, |client| {
+        widget(&mut client.region(0, 0, 4, 3));
+        widget(&mut client.region(4, 1, 4, 3));
+    })
+    .await;
+    assert_eq!(screen, "┌──┐\n│hi│┌──┐\n└──┘│hi│\n    └──┘\n");
+}
+
+#[tokio::test]
+async fn wide_characters_which_do_not_fit_are_left_out() {
+    let screen = common::draw(8, 5, |client| {
+        client.region(0, 0, 3, 1).print(0, 0, "你好");
+    })
+    .await;
+    assert_eq!(screen.lines().next(), Some("你"));
+}
+
+#[tokio::test]
+async fn text_stops_at_the_last_coordinate() {
+    common::draw(8, 5, |client| {
+        client.print(u32::MAX - 1, u32::MAX, "abc\nde");
+        assert_eq!(client.get(u32::MAX, u32::MAX), Some(Cell::new('b')));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn lines_and_rectangles_stop_at_the_last_coordinate() {
+    const MAX: u32 = u32::MAX;
+    common::draw(8, 5, |client| {
+        client.hline(MAX - 1, 0, 10, BorderStyle::Single);
+        client.vline(0, MAX - 1, 10, BorderStyle::Single);
+        client.rect(MAX - 2, MAX - 2, 10, 10, BorderStyle::Single);
+        client.fill(MAX - 1, 5, 10, 2, Cell::new('#'));
+        assert_eq!(client.get(MAX, 0), Some(Cell::new('─')));
+        assert_eq!(client.get(0, MAX), Some(Cell::new('│')));
+        assert_eq!(client.get(MAX, MAX), Some(Cell::new('┘')));
+        assert_eq!(client.get(MAX, 6), Some(Cell::new('#')));
+    })
+    .await;
+}
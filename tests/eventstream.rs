@@ -0,0 +1,128 @@
+use libccanvas::{
+    bindings::{
+        Discriminator, EventVariant, KeyCode, KeyEvent, KeyModifier, MouseEvent, MouseType,
+    },
+    client::{Client, EventFilter},
+    testing::MockCanvas,
+};
+
+fn key(c: char) -> EventVariant {
+    EventVariant::Key(KeyEvent::new(KeyCode::Char(c), KeyModifier::None))
+}
+
+fn mouse() -> EventVariant {
+    EventVariant::Mouse(MouseEvent {
+        x: 1,
+        y: 2,
+        mousetype: MouseType::Left,
+    })
+}
+
+fn message(sender: Discriminator) -> EventVariant {
+    EventVariant::Message {
+        sender,
+        target: Discriminator::master(),
+        content: "hi".to_string(),
+    }
+}
+
+fn resize() -> EventVariant {
+    EventVariant::Resize {
+        width: 80,
+        height: 24,
+    }
+}
+
+#[test]
+fn filters_match_their_events() {
+    let events = [
+        key('a'),
+        mouse(),
+        message(Discriminator::new(vec![1, 2])),
+        resize(),
+        EventVariant::Focused,
+        EventVariant::Unfocused,
+    ];
+    let cases = [
+        (
+            EventFilter::Keys,
+            vec![true, false, false, false, false, false],
+        ),
+        (
+            EventFilter::Mouse,
+            vec![false, true, false, false, false, false],
+        ),
+        (
+            EventFilter::Messages,
+            vec![false, false, true, false, false, false],
+        ),
+        (
+            EventFilter::MessagesFrom(Discriminator::new(vec![1, 2])),
+            vec![false, false, true, false, false, false],
+        ),
+        (
+            EventFilter::MessagesFrom(Discriminator::new(vec![1, 3])),
+            vec![false, false, false, false, false, false],
+        ),
+        (
+            EventFilter::Resize,
+            vec![false, false, false, true, false, false],
+        ),
+        (
+            EventFilter::Focus,
+            vec![false, false, false, false, true, true],
+        ),
+        (
+            EventFilter::custom(|event| matches!(event, EventVariant::Unfocused)),
+            vec![false, false, false, false, false, true],
+        ),
+    ];
+
+    for (filter, expected) in cases {
+        let matched: Vec<bool> = events.iter().map(|event| filter.matches(event)).collect();
+        assert_eq!(matched, expected, "{filter:?}");
+    }
+}
+
+#[tokio::test]
+async fn split_takes_matching_events() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+    let mut keys = client.events().split(EventFilter::Keys);
+
+    canvas.send_event(key('a')).await.unwrap();
+    canvas.send_event(resize()).await.unwrap();
+
+    // events are routed in order, so the key has been routed once the resize arrives
+    assert_eq!(client.recv().await.unwrap().get(), &resize());
+    assert_eq!(keys.recv().await.unwrap().get(), &key('a'));
+    assert!(client.events().try_recv().is_none());
+}
+
+#[tokio::test]
+async fn most_recent_split_wins() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+    let mut older = client.events().split(EventFilter::Keys);
+    let mut newer = client.events().split(EventFilter::Keys);
+
+    canvas.send_event(key('a')).await.unwrap();
+    canvas.send_event(resize()).await.unwrap();
+
+    assert_eq!(client.recv().await.unwrap().get(), &resize());
+    assert_eq!(newer.recv().await.unwrap().get(), &key('a'));
+    assert!(older.try_recv().is_none());
+}
+
+#[tokio::test]
+async fn events_return_to_main_once_the_sub_stream_is_dropped() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+    let keys = client.events().split(EventFilter::Keys);
+    drop(keys);
+
+    let id = canvas.send_event(key('a')).await.unwrap();
+
+    assert_eq!(client.recv().await.unwrap().get(), &key('a'));
+    canvas.assert_confirmed(id, true).await;
+}
@@ -0,0 +1,80 @@
+use libccanvas::bindings::{encode_frame, FrameError, FrameReader};
+
+#[tokio::test]
+async fn reads_consecutive_frames() {
+    let mut bytes = encode_frame(&"first").unwrap();
+    bytes.extend(encode_frame(&"second").unwrap());
+
+    let mut reader = FrameReader::new(bytes.as_slice());
+    assert_eq!(reader.next::<String>().await.unwrap().unwrap(), "first");
+    assert_eq!(reader.next::<String>().await.unwrap().unwrap(), "second");
+    assert!(reader.next::<String>().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn reports_partial_frames() {
+    let bytes = encode_frame(&"truncated").unwrap();
+
+    let mut reader = FrameReader::new(&bytes[..bytes.len() - 1]);
+    assert!(matches!(
+        reader.next::<String>().await,
+        Err(FrameError::Partial {
+            expected: 11,
+            received: 10
+        })
+    ));
+
+    let mut reader = FrameReader::new(&bytes[..2]);
+    assert!(matches!(
+        reader.next::<String>().await,
+        Err(FrameError::Partial {
+            expected: 4,
+            received: 2
+        })
+    ));
+}
+
+#[tokio::test]
+async fn reports_oversized_frames() {
+    let bytes = encode_frame(&"too long").unwrap();
+
+    let mut reader = FrameReader::new(bytes.as_slice()).with_max_len(4);
+    assert!(matches!(
+        reader.next::<String>().await,
+        Err(FrameError::Oversized { len: 10, max: 4 })
+    ));
+}
+
+#[tokio::test]
+async fn skips_malformed_frames() {
+    let mut bytes = encode_frame(&42).unwrap();
+    bytes.extend(encode_frame(&"valid").unwrap());
+
+    let mut reader = FrameReader::new(bytes.as_slice());
+    assert!(matches!(
+        reader.next::<String>().await,
+        Err(FrameError::Malformed(_))
+    ));
+    assert_eq!(reader.next::<String>().await.unwrap().unwrap(), "valid");
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn detects_msgpack_frames() {
+    use libccanvas::bindings::{encode_frame_with, Encoding};
+    use std::collections::HashMap;
+
+    let value = HashMap::from([("x".to_string(), 1_u32)]);
+    let mut bytes = encode_frame_with(&value, Encoding::MessagePack).unwrap();
+    bytes.extend(encode_frame_with(&value, Encoding::Json).unwrap());
+
+    let mut reader = FrameReader::new(bytes.as_slice());
+    assert_eq!(
+        reader.next::<HashMap<String, u32>>().await.unwrap(),
+        Some(value.clone())
+    );
+    assert_eq!(
+        reader.next::<HashMap<String, u32>>().await.unwrap(),
+        Some(value)
+    );
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use libccanvas::{
+    bindings::{Cell, Colour, RenderRequest},
+    client::FrameBuffer,
+    testing::MockCanvas,
+};
+
+#[test]
+fn only_changed_cells_are_flushed() {
+    let mut frame = FrameBuffer::new();
+    frame.set(1, 0, Cell::new('a'));
+    frame.set(0, 1, Cell::coloured('b', Colour::Red, Colour::Reset));
+    assert_eq!(
+        frame.flush(),
+        vec![
+            RenderRequest::setchar(1, 0, 'a'),
+            RenderRequest::setchar_coloured(0, 1, 'b', Colour::Red, Colour::Reset),
+        ]
+    );
+
+    frame.set(1, 0, Cell::new('a'));
+    frame.set(0, 1, Cell::new('b'));
+    assert!(frame.is_dirty());
+    assert_eq!(frame.flush(), vec![RenderRequest::setchar(0, 1, 'b')]);
+    assert!(!frame.is_dirty());
+    assert_eq!(frame.flush(), Vec::new());
+}
+
+#[test]
+fn repeated_writes_are_merged() {
+    let mut frame = FrameBuffer::new();
+    frame.set(0, 0, Cell::new('a'));
+    frame.set(0, 0, Cell::new('b'));
+    assert_eq!(frame.get(0, 0), Some(&Cell::new('b')));
+    assert_eq!(frame.flush(), vec![RenderRequest::setchar(0, 0, 'b')]);
+
+    // drawn and then put back before the flush
+    frame.set(0, 0, Cell::new('c'));
+    frame.set(0, 0, Cell::new('b'));
+    assert_eq!(frame.flush(), Vec::new());
+}
+
+#[test]
+fn drawing_over_the_right_half_of_a_wide_character_erases_it() {
+    let mut frame = FrameBuffer::new();
+    frame.set(0, 0, Cell::new('你'));
+    frame.flush();
+
+    frame.set(1, 0, Cell::new('b'));
+    assert_eq!(frame.get(0, 0), None);
+    assert_eq!(frame.flush(), vec![RenderRequest::setchar(1, 0, 'b')]);
+
+    // the terminal no longer shows it, so drawing it again is sent
+    frame.set(0, 0, Cell::new('你'));
+    assert_eq!(frame.flush(), vec![RenderRequest::setchar(0, 0, '你')]);
+
+    // wide characters at the last column have nothing to cover
+    frame.set(u32::MAX, 0, Cell::new('好'));
+    assert_eq!(frame.get(u32::MAX, 0), Some(&Cell::new('好')));
+}
+
+#[test]
+fn invalidate_sends_everything_again() {
+    let mut frame = FrameBuffer::new();
+    frame.set(0, 0, Cell::new('a'));
+    frame.set(1, 0, Cell::new('b'));
+    frame.flush();
+
+    frame.invalidate();
+    frame.set(1, 0, Cell::new('c'));
+    assert_eq!(
+        frame.flush(),
+        vec![
+            RenderRequest::setchar(0, 0, 'a'),
+            RenderRequest::setchar(1, 0, 'c'),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn renderall_sends_the_difference() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = common::connect(&canvas).await;
+
+    for (x, c) in "hello".chars().enumerate() {
+        client.setchar(x as u32, 0, c);
+    }
+    client.hidecursor();
+    client.renderall().await.unwrap();
+
+    for (x, c) in "help".chars().enumerate() {
+        client.setchar(x as u32, 0, c);
+    }
+    client.renderall().await.unwrap();
+
+    // nothing changed, so nothing is sent
+    client.setchar(0, 0, 'h');
+    client.renderall().await.unwrap();
+
+    let renders = common::renders(&canvas);
+    assert_eq!(renders.len(), 2);
+    assert_eq!(renders[0].len(), 6);
+    assert_eq!(renders[0][5], RenderRequest::HideCursor);
+    assert_eq!(renders[1], vec![RenderRequest::setchar(3, 0, 'p')]);
+    assert_eq!(canvas.screen().to_plain().lines().next(), Some("helpo"));
+
+    client.invalidate();
+    client.renderall().await.unwrap();
+    assert_eq!(common::renders(&canvas)[2].len(), 5);
+}
@@ -0,0 +1,419 @@
+//! pins the exact json of every binding type
+//!
+//! each golden file holds an array of values in the order they are listed here,
+//! run with `UPDATE_GOLDEN=1` to rewrite the files after an intended change to the wire format
+
+use std::{fmt::Debug, path::PathBuf};
+
+use libccanvas::bindings::{
+    Attributes, Capability, Colour, CursorStyle, Discriminator, Encoding, EventVariant, KeyCode,
+    KeyEvent, KeyModifier, MouseEvent, MouseType, RawJson, RenderRequest, Request, RequestContent,
+    RequestIds, Response, ResponseContent, ResponseError, ResponseSuccess, Style, Subscription,
+    PROTOCOL_VERSION,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+fn check<T: Serialize + DeserializeOwned + PartialEq + Debug>(name: &str, values: Vec<T>) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.json"));
+    let serialised = serde_json::to_value(&values).unwrap();
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let mut pretty = serde_json::to_string_pretty(&serialised).unwrap();
+        pretty.push('\n');
+        std::fs::write(&path, pretty).unwrap();
+        return;
+    }
+
+    let golden: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(
+        serde_json::to_string_pretty(&serialised).unwrap(),
+        serde_json::to_string_pretty(&golden).unwrap(),
+        "{name} serialises differently from {}",
+        path.display()
+    );
+
+    let deserialised: Vec<T> = serde_json::from_value(golden).unwrap();
+    assert_eq!(deserialised, values, "{name} does not round trip");
+}
+
+fn discrim() -> Discriminator {
+    Discriminator::new(vec![1, 2, 3])
+}
+
+fn key() -> KeyEvent {
+    KeyEvent::new(KeyCode::Char('q'), KeyModifier::Ctrl)
+}
+
+#[test]
+fn request() {
+    let ids = RequestIds::starting_after(41);
+    check(
+        "request",
+        vec![Request::new(discrim(), RequestContent::FocusAt, &ids)],
+    );
+}
+
+/// one of every request variant
+fn request_contents() -> Vec<RequestContent> {
+    vec![
+        RequestContent::ConfirmRecieve { id: 5, pass: true },
+        RequestContent::Subscribe {
+            channel: Subscription::AllKeyPresses,
+            priority: Some(10),
+            component: Some(discrim()),
+        },
+        RequestContent::Unsubscribe {
+            channel: Subscription::AllKeyPresses,
+            component: None,
+        },
+        RequestContent::SetSocket {
+            path: PathBuf::from("/tmp/listen.sock"),
+            encodings: vec![Encoding::Json],
+            protocol: PROTOCOL_VERSION,
+            capabilities: vec![Capability::UNKNOWN_VARIANTS],
+        },
+        RequestContent::Drop { discrim: None },
+        RequestContent::Render {
+            content: RenderRequest::Flush,
+            flush: true,
+        },
+        RequestContent::Spawn {
+            command: "ccanvas-snake".to_string(),
+            args: vec!["--fast".to_string()],
+            label: "snake".to_string(),
+        },
+        RequestContent::Message {
+            content: "hello".to_string(),
+            sender: Discriminator::default(),
+            target: discrim(),
+        },
+        RequestContent::NewSpace {
+            label: "canvas".to_string(),
+        },
+        RequestContent::FocusAt,
+    ]
+}
+
+#[test]
+fn request_content() {
+    check("request_content", request_contents());
+}
+
+#[test]
+fn request_kind_is_the_serialised_tag() {
+    for content in request_contents() {
+        assert_eq!(
+            serde_json::to_value(&content).unwrap()["type"],
+            content.kind(),
+            "{content:?}"
+        );
+    }
+}
+
+#[test]
+fn unsubscribe_accepts_both_tags() {
+    for tag in ["Unsubscribe", "unsubscribe"] {
+        let content: RequestContent = serde_json::from_value(json!({
+            "type": tag,
+            "channel": { "type": "focused" },
+            "component": null,
+        }))
+        .unwrap();
+        assert_eq!(
+            content,
+            RequestContent::Unsubscribe {
+                channel: Subscription::Focused,
+                component: None
+            }
+        );
+    }
+}
+
+#[test]
+fn render_request() {
+    check(
+        "render_request",
+        vec![
+            RenderRequest::setchar(1, 2, 'a'),
+            RenderRequest::setchar_coloured(3, 4, 'b', Colour::Red, Colour::Reset),
+            RenderRequest::setchar_styled(
+                5,
+                6,
+                'c',
+                Style::new()
+                    .with_colours(Colour::Blue, Colour::Reset)
+                    .with_attributes(Attributes::BOLD | Attributes::UNDERLINE),
+            ),
+            RenderRequest::Flush,
+            RenderRequest::setcursor(CursorStyle::BlinkingBar),
+            RenderRequest::HideCursor,
+            RenderRequest::ShowCursor,
+            RenderRequest::RenderMultiple {
+                tasks: vec![RenderRequest::setchar(0, 0, 'c')],
+            },
+        ],
+    );
+}
+
+#[test]
+fn style() {
+    check(
+        "style",
+        vec![
+            Style::new(),
+            Style::new()
+                .with_colours(Colour::Red, Colour::Black)
+                .with_attributes(
+                    Attributes::BOLD
+                        | Attributes::DIM
+                        | Attributes::ITALIC
+                        | Attributes::UNDERLINE
+                        | Attributes::REVERSE
+                        | Attributes::STRIKETHROUGH,
+                ),
+        ],
+    );
+}
+
+#[test]
+fn cursor_style() {
+    check(
+        "cursor_style",
+        vec![
+            CursorStyle::BlinkingBar,
+            CursorStyle::BlinkingBlock,
+            CursorStyle::BlinkingUnderline,
+            CursorStyle::SteadyBar,
+            CursorStyle::SteadyBlock,
+            CursorStyle::SteadyUnderline,
+        ],
+    );
+}
+
+#[test]
+fn colour() {
+    check(
+        "colour",
+        vec![
+            Colour::Black,
+            Colour::Blue,
+            Colour::Cyan,
+            Colour::Green,
+            Colour::Magenta,
+            Colour::Red,
+            Colour::White,
+            Colour::Yellow,
+            Colour::LightBlack,
+            Colour::LightBlue,
+            Colour::LightCyan,
+            Colour::LightGreen,
+            Colour::LightMagenta,
+            Colour::LightRed,
+            Colour::LightWhite,
+            Colour::LightYellow,
+            Colour::Reset,
+            Colour::Ansi { value: 200 },
+            Colour::Rgb {
+                red: 1,
+                green: 2,
+                blue: 3,
+            },
+        ],
+    );
+}
+
+#[test]
+fn subscription() {
+    check(
+        "subscription",
+        vec![
+            Subscription::AllKeyPresses,
+            Subscription::AllMouseEvents,
+            Subscription::AllMessages,
+            Subscription::specific_keypress(key()),
+            Subscription::specific_keymodifier(KeyModifier::Alt),
+            Subscription::specific_keycode(KeyCode::Esc),
+            Subscription::specific_mouse(MouseType::Left),
+            Subscription::specific_message(discrim()),
+            Subscription::ScreenResize,
+            Subscription::Focused,
+            Subscription::Unfocused,
+            Subscription::Multiple {
+                subs: vec![
+                    (Subscription::AllKeyPresses, None),
+                    (Subscription::ScreenResize, Some(3)),
+                ],
+            },
+        ],
+    );
+}
+
+#[test]
+fn response() {
+    check(
+        "response",
+        vec![
+            Response {
+                content: ResponseContent::Success {
+                    content: ResponseSuccess::Rendered,
+                },
+                id: 0,
+                request: Some(42),
+            },
+            Response {
+                content: ResponseContent::Event {
+                    content: EventVariant::Focused,
+                },
+                id: 7,
+                request: None,
+            },
+        ],
+    );
+}
+
+#[test]
+fn response_content() {
+    check(
+        "response_content",
+        vec![
+            ResponseContent::Undelivered,
+            ResponseContent::Event {
+                content: EventVariant::Unfocused,
+            },
+            ResponseContent::Error {
+                content: ResponseError::ComponentNotFound,
+            },
+            ResponseContent::Success {
+                content: ResponseSuccess::FocusChanged,
+            },
+        ],
+    );
+}
+
+#[test]
+fn response_error() {
+    check(
+        "response_error",
+        vec![
+            ResponseError::ComponentNotFound,
+            ResponseError::SpawnFailed,
+            ResponseError::Unknown(RawJson(json!({ "type": "from the future" }))),
+        ],
+    );
+}
+
+#[test]
+fn response_success() {
+    check(
+        "response_success",
+        vec![
+            ResponseSuccess::SubscribeAdded,
+            ResponseSuccess::SubscribeRemoved,
+            ResponseSuccess::ListenerSet {
+                encoding: Encoding::Json,
+                protocol: Some(PROTOCOL_VERSION),
+                capabilities: vec![Capability::UNKNOWN_VARIANTS],
+            },
+            ResponseSuccess::Dropped,
+            ResponseSuccess::Rendered,
+            ResponseSuccess::Spawned { discrim: discrim() },
+            ResponseSuccess::MessageDelivered,
+            ResponseSuccess::SpaceCreated { discrim: discrim() },
+            ResponseSuccess::FocusChanged,
+            ResponseSuccess::Unknown(RawJson(json!({ "type": "from the future" }))),
+        ],
+    );
+}
+
+#[test]
+fn event_variant() {
+    check(
+        "event_variant",
+        vec![
+            EventVariant::Key(key()),
+            EventVariant::Mouse(MouseEvent {
+                x: 4,
+                y: 5,
+                mousetype: MouseType::Hold,
+            }),
+            EventVariant::Resize {
+                width: 80,
+                height: 24,
+            },
+            EventVariant::Message {
+                sender: discrim(),
+                target: Discriminator::master(),
+                content: "hello".to_string(),
+            },
+            EventVariant::Focused,
+            EventVariant::Unfocused,
+            EventVariant::Unknown(RawJson(json!({ "type": "paste", "text": "hi" }))),
+        ],
+    );
+}
+
+#[test]
+fn key_code() {
+    check(
+        "key_code",
+        vec![
+            KeyCode::Backspace,
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Home,
+            KeyCode::End,
+            KeyCode::PageUp,
+            KeyCode::PageDown,
+            KeyCode::BackTab,
+            KeyCode::Delete,
+            KeyCode::Insert,
+            KeyCode::F(5),
+            KeyCode::Char('x'),
+            KeyCode::Null,
+            KeyCode::Esc,
+        ],
+    );
+}
+
+#[test]
+fn key_modifier() {
+    check(
+        "key_modifier",
+        vec![KeyModifier::Alt, KeyModifier::Ctrl, KeyModifier::None],
+    );
+}
+
+#[test]
+fn mouse_type() {
+    check(
+        "mouse_type",
+        vec![
+            MouseType::Left,
+            MouseType::Right,
+            MouseType::Middle,
+            MouseType::WheelUp,
+            MouseType::WheelDown,
+            MouseType::Release,
+            MouseType::Hold,
+        ],
+    );
+}
+
+#[test]
+fn handshake_types() {
+    check("encoding", vec![Encoding::Json]);
+    check(
+        "capability",
+        vec![
+            Capability::UNKNOWN_VARIANTS,
+            Capability::STYLED_CHARS,
+            Capability::new("custom"),
+        ],
+    );
+    check("discriminator", vec![Discriminator::default(), discrim()]);
+}
@@ -0,0 +1,38 @@
+use libccanvas::{
+    bindings::{Discriminator, EventVariant, RenderRequest, RequestContent},
+    client::Client,
+    testing::MockCanvas,
+};
+
+#[tokio::test]
+async fn handles_send_from_other_tasks_while_the_client_waits_for_events() {
+    let canvas = MockCanvas::new().await.unwrap().with_screen_size(4, 1);
+    let mut client = Client::new(canvas.config()).await.unwrap();
+
+    let handle = client.handle();
+    let task = tokio::spawn(async move {
+        handle
+            .message(Discriminator::master(), "hi".to_string())
+            .await
+            .unwrap();
+        handle
+            .render(vec![RenderRequest::setchar(0, 0, 'a')])
+            .await
+            .unwrap();
+    });
+
+    // the event is only sent once the task is done, so the client waits in recv the whole time
+    let (event, ()) = tokio::join!(client.recv(), async {
+        task.await.unwrap();
+        canvas.send_event(EventVariant::Focused).await.unwrap();
+    });
+
+    assert_eq!(event.unwrap().get(), &EventVariant::Focused);
+    let requests = canvas.requests();
+    assert!(matches!(
+        requests[1].content,
+        RequestContent::Message { .. }
+    ));
+    assert!(matches!(requests[2].content, RequestContent::Render { .. }));
+    assert_eq!(canvas.screen().to_plain(), "a");
+}
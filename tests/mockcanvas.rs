@@ -0,0 +1,73 @@
+use libccanvas::{
+    bindings::{
+        Discriminator, EventVariant, KeyCode, KeyEvent, KeyModifier, RequestContent,
+        ResponseContent,
+    },
+    client::Client,
+    testing::{MockCanvas, Reply},
+};
+
+fn key(c: char) -> EventVariant {
+    EventVariant::Key(KeyEvent::new(KeyCode::Char(c), KeyModifier::None))
+}
+
+#[tokio::test]
+async fn records_requests_in_order() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    client.focus_at(Discriminator::master()).await.unwrap();
+    client
+        .message(Discriminator::master(), "hi".to_string())
+        .await
+        .unwrap();
+
+    let requests = canvas.requests();
+    assert!(matches!(
+        requests[0].content,
+        RequestContent::SetSocket { .. }
+    ));
+    assert_eq!(requests[1].content, RequestContent::FocusAt);
+    assert_eq!(requests[1].target(), &Discriminator::master());
+    assert!(matches!(
+        requests[2].content,
+        RequestContent::Message { .. }
+    ));
+}
+
+#[tokio::test]
+async fn responder_decides_unscripted_replies() {
+    let canvas = MockCanvas::new().await.unwrap();
+    canvas.respond_with(|req| match req.content {
+        RequestContent::FocusAt if req.target() == &Discriminator::new(vec![1, 9]) => {
+            Reply::Respond(ResponseContent::Undelivered)
+        }
+        _ => Reply::Default,
+    });
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    assert!(client.focus_at(Discriminator::master()).await.is_ok());
+    assert!(client
+        .focus_at(Discriminator::new(vec![1, 9]))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn injected_events_reach_the_client_and_are_confirmed() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+
+    let passed = canvas.send_event(key('a')).await.unwrap();
+    let captured = canvas.send_event(key('b')).await.unwrap();
+
+    let event = client.recv().await.unwrap();
+    assert_eq!(event.get(), &key('a'));
+    drop(event);
+    canvas.assert_confirmed(passed, true).await;
+
+    let mut event = client.recv().await.unwrap();
+    assert_eq!(event.get(), &key('b'));
+    event.done(false).unwrap();
+    canvas.assert_confirmed(captured, false).await;
+}
@@ -0,0 +1,73 @@
+use libccanvas::{
+    bindings::{Discriminator, RenderRequest, RequestContent, Subscription},
+    client::Client,
+    testing::MockCanvas,
+};
+
+#[tokio::test]
+async fn requests_are_sent_before_being_awaited() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+    canvas.next_request().await.unwrap();
+
+    let pending: Vec<_> = (0..10)
+        .map(|_| client.focus_at(Discriminator::default()))
+        .collect();
+
+    // all requests arrive while none of them are being awaited yet
+    let mut ids = Vec::new();
+    for _ in 0..10 {
+        let req = canvas.next_request().await.unwrap();
+        assert_eq!(req.content, RequestContent::FocusAt);
+        ids.push(req.id());
+    }
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    for res in pending {
+        assert!(res.await.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn dropped_responses_still_send_the_request() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+    canvas.next_request().await.unwrap();
+
+    drop(client.message(Discriminator::master(), "hello".to_string()));
+
+    assert!(matches!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Message { content, .. } if content == "hello"
+    ));
+}
+
+#[tokio::test]
+async fn requests_arrive_in_the_order_they_were_sent() {
+    let mut canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+    canvas.next_request().await.unwrap();
+
+    let subscribe = client.subscribe(Subscription::AllKeyPresses);
+    let message = client.message(Discriminator::master(), "hello".to_string());
+    let render = client.render(vec![RenderRequest::SetChar { x: 0, y: 0, c: 'a' }]);
+
+    assert!(matches!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Subscribe {
+            channel: Subscription::AllKeyPresses,
+            ..
+        }
+    ));
+    assert!(matches!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Message { content, .. } if content == "hello"
+    ));
+    assert!(matches!(
+        canvas.next_request().await.unwrap().content,
+        RequestContent::Render { .. }
+    ));
+
+    let (subscribe, message, render) = tokio::join!(subscribe, message, render);
+    assert!(subscribe.is_ok() && message.is_ok() && render.is_ok());
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use libccanvas::{
+    bindings::{Cell, Colour, Style},
+    client::{text_width, Align, Overflow, Surface, TextStyle},
+    testing::MockCanvas,
+};
+
+/// print into a fresh canvas, returns the rows drawn and the screen as plain text
+async fn print(text: &str, style: TextStyle) -> (u32, String) {
+    let mut rows = 0;
+    let screen = common::draw(12, 6, |client| {
+        rows = client.print_styled(1, 1, text, &style);
+    })
+    .await;
+    (rows, screen)
+}
+
+#[tokio::test]
+async fn prints_lines() {
+    let (rows, screen) = print("hello\nworld", TextStyle::new()).await;
+    assert_eq!(rows, 2);
+    assert_eq!(screen, "\n hello\n world\n\n\n");
+}
+
+#[tokio::test]
+async fn aligns_inside_width() {
+    let (_, screen) = print(
+        "ab\nabcd",
+        TextStyle::new().with_width(7).with_align(Align::Right),
+    )
+    .await;
+    assert_eq!(screen, "\n      ab\n    abcd\n\n\n");
+
+    let (_, screen) = print(
+        "ab\nabc",
+        TextStyle::new().with_width(7).with_align(Align::Centre),
+    )
+    .await;
+    assert_eq!(screen, "\n   ab\n   abc\n\n\n");
+}
+
+#[tokio::test]
+async fn cuts_off_long_lines() {
+    let (rows, screen) = print("hello world", TextStyle::new().with_width(5)).await;
+    assert_eq!(rows, 1);
+    assert_eq!(screen, "\n hello\n\n\n\n");
+
+    let (_, screen) = print(
+        "hello world",
+        TextStyle::new()
+            .with_width(5)
+            .with_overflow(Overflow::Ellipsis),
+    )
+    .await;
+    assert_eq!(screen, "\n hell…\n\n\n\n");
+
+    let (_, screen) = print(
+        "hello",
+        TextStyle::new()
+            .with_width(5)
+            .with_overflow(Overflow::Ellipsis),
+    )
+    .await;
+    assert_eq!(screen, "\n hello\n\n\n\n");
+}
+
+#[tokio::test]
+async fn wraps_between_words() {
+    let (rows, screen) = print(
+        "the quick brown fox\nabcdefghijkl",
+        TextStyle::new()
+            .with_width(10)
+            .with_overflow(Overflow::Wrap),
+    )
+    .await;
+    assert_eq!(rows, 4);
+    assert_eq!(screen, "\n the quick\n brown fox\n abcdefghij\n kl\n");
+}
+
+#[tokio::test]
+async fn wide_characters_take_two_columns() {
+    assert_eq!(text_width("a你好🦀"), 7);
+
+    let canvas = MockCanvas::new().await.unwrap().with_screen_size(6, 3);
+    let mut client = common::connect(&canvas).await;
+    client.print_styled(
+        0,
+        0,
+        "你好!",
+        &TextStyle::new().with_style(Style::new().with_colours(Colour::Red, Colour::Reset)),
+    );
+
+    let frame = client.frame();
+    let red = |c| Cell::coloured(c, Colour::Red, Colour::Reset);
+    assert_eq!(frame.get(0, 0), Some(&red('你')));
+    assert_eq!(frame.get(1, 0), None);
+    assert_eq!(frame.get(2, 0), Some(&red('好')));
+    assert_eq!(frame.get(4, 0), Some(&red('!')));
+
+    // wrapping and alignment count columns, not characters
+    let rows = client.print_styled(
+        0,
+        1,
+        "你好你",
+        &TextStyle::new()
+            .with_width(5)
+            .with_align(Align::Right)
+            .with_overflow(Overflow::Wrap),
+    );
+    assert_eq!(rows, 2);
+    assert_eq!(client.frame().get(1, 1), Some(&Cell::new('你')));
+    assert_eq!(client.frame().get(3, 2), Some(&Cell::new('你')));
+
+    client.renderall().await.unwrap();
+    assert_eq!(canvas.screen().to_plain(), "你好!\n 你好\n   你");
+}
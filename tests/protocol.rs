@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use libccanvas::{
+    bindings::{Capability, RequestContent, PROTOCOL_VERSION},
+    client::{Client, ClientError},
+    testing::{MockCanvas, Reply},
+};
+
+#[tokio::test]
+async fn handshake_carries_version_and_capabilities() {
+    let mut canvas = MockCanvas::new().await.unwrap().with_protocol(None);
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    match canvas.next_request().await.unwrap().content {
+        RequestContent::SetSocket {
+            protocol,
+            capabilities,
+            ..
+        } => {
+            assert_eq!(protocol, PROTOCOL_VERSION);
+            assert!(capabilities.contains(&Capability::UNKNOWN_VARIANTS));
+        }
+        content => panic!("expected set socket, got {content:?}"),
+    }
+
+    // servers from before versioning already read frames,
+    // so they are assumed to be compatible, without any capabilities
+    assert_eq!(client.protocol(), None);
+    assert!(!client.supports(&Capability::UNKNOWN_VARIANTS));
+}
+
+#[tokio::test]
+async fn server_capabilities_can_be_checked() {
+    let canvas = MockCanvas::new().await.unwrap().with_capabilities(vec![
+        Capability::UNKNOWN_VARIANTS,
+        Capability::new("teleportation"),
+    ]);
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    assert_eq!(client.protocol(), Some(PROTOCOL_VERSION));
+    assert!(client.supports(&Capability::UNKNOWN_VARIANTS));
+    assert!(client.supports(&Capability::new("teleportation")));
+    assert!(!client.supports(&Capability::new("time travel")));
+}
+
+#[tokio::test]
+async fn incompatible_servers_are_rejected() {
+    let canvas = MockCanvas::new()
+        .await
+        .unwrap()
+        .with_protocol(Some(PROTOCOL_VERSION + 1));
+
+    match Client::new(canvas.config()).await {
+        Err(ClientError::Incompatible { client, server }) => {
+            assert_eq!(client, PROTOCOL_VERSION);
+            assert_eq!(server, PROTOCOL_VERSION + 1);
+        }
+        Err(e) => panic!("expected an incompatible error, got {e}"),
+        Ok(_) => panic!("expected an incompatible error"),
+    }
+}
+
+#[tokio::test]
+async fn servers_which_cannot_read_frames_time_out() {
+    let canvas = MockCanvas::new().await.unwrap();
+    // a canvas from before framing cannot read the set socket request, so it never answers
+    canvas.respond_with(|_| Reply::Ignore);
+    let config = canvas
+        .config()
+        .with_request_timeout(Some(Duration::from_millis(50)));
+
+    assert!(matches!(
+        Client::new(config).await,
+        Err(ClientError::Timeout { .. })
+    ));
+}
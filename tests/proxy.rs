@@ -0,0 +1,156 @@
+use std::{
+    io::Write,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use libccanvas::{
+    bindings::{
+        Discriminator, EventVariant, KeyCode, KeyEvent, KeyModifier, RequestContent,
+        ResponseContent, ResponseSuccess,
+    },
+    client::Client,
+    proxy::{LogEntry, Message, Proxy, ProxyConfig},
+    testing::MockCanvas,
+};
+
+/// a log which can be read while the proxy is still writing to it
+#[derive(Clone, Default)]
+struct SharedLog(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedLog {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedLog {
+    fn entries(&self) -> Vec<LogEntry> {
+        String::from_utf8(self.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+}
+
+fn socket(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join("ccanvas")
+        .join(format!("proxy-{}-{name}.sock", std::process::id()))
+}
+
+/// run a proxy in the background, it stops when the handle is aborted
+async fn start(config: ProxyConfig) -> (SharedLog, tokio::task::JoinHandle<()>) {
+    let _ = std::fs::remove_file(&config.request_socket);
+    let log = SharedLog::default();
+    let proxy = Proxy::bind(config, log.clone()).await.unwrap();
+    let handle = tokio::spawn(async move {
+        let _ = proxy.run().await;
+    });
+    (log, handle)
+}
+
+#[tokio::test]
+async fn forwards_and_logs_traffic() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let proxy_socket = socket("forwards");
+    let (log, proxy) = start(ProxyConfig::new(&proxy_socket, canvas.request_socket())).await;
+
+    let mut config = canvas.config();
+    let listener_socket = config.listener_socket.clone();
+    config.request_socket = proxy_socket.clone();
+    let mut client = Client::new(config).await.unwrap();
+
+    client.focus_at(Discriminator::master()).await.unwrap();
+    let event = canvas
+        .send_event(EventVariant::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifier::None,
+        )))
+        .await
+        .unwrap();
+    client.recv().await.unwrap().done(true).unwrap();
+    canvas.assert_confirmed(event, true).await;
+
+    // the canvas only ever sees the listener socket of the proxy
+    match &canvas.requests()[0].content {
+        RequestContent::SetSocket { path, .. } => assert_ne!(path, &listener_socket),
+        content => panic!("expected set socket, got {content:?}"),
+    }
+
+    let entries = log.entries();
+    assert!(entries.iter().all(|entry| entry.connection == 1));
+    let messages = entries
+        .into_iter()
+        .map(|entry| entry.message)
+        .collect::<Vec<_>>();
+
+    // the log shows the request as the component sent it
+    assert!(matches!(
+        &messages[0],
+        Message::Request(req) if matches!(
+            &req.content,
+            RequestContent::SetSocket { path, .. } if path == &listener_socket
+        )
+    ));
+    assert!(matches!(
+        &messages[1],
+        Message::Response(res) if matches!(
+            res.content,
+            ResponseContent::Success { content: ResponseSuccess::ListenerSet { .. } }
+        )
+    ));
+    assert!(
+        matches!(&messages[2], Message::Request(req) if req.content == RequestContent::FocusAt)
+    );
+    assert!(matches!(&messages[3], Message::Response(_)));
+    assert!(matches!(&messages[4], Message::Event(res) if res.id == event));
+    assert!(matches!(
+        &messages[5],
+        Message::Request(req) if req.content == RequestContent::ConfirmRecieve { id: event, pass: true }
+    ));
+
+    drop(client);
+    proxy.abort();
+}
+
+#[tokio::test]
+async fn filters_by_request_type() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let proxy_socket = socket("filters");
+    let (log, proxy) =
+        start(ProxyConfig::new(&proxy_socket, canvas.request_socket()).with_filter(["focus at"]))
+            .await;
+
+    let mut config = canvas.config();
+    config.request_socket = proxy_socket.clone();
+    let client = Client::new(config).await.unwrap();
+
+    client
+        .message(Discriminator::master(), "hi".to_string())
+        .await
+        .unwrap();
+    client.focus_at(Discriminator::master()).await.unwrap();
+
+    let messages = log
+        .entries()
+        .into_iter()
+        .map(|entry| entry.message)
+        .collect::<Vec<_>>();
+    assert_eq!(messages.len(), 2);
+    assert!(
+        matches!(&messages[0], Message::Request(req) if req.content == RequestContent::FocusAt)
+    );
+    assert!(matches!(
+        &messages[1],
+        Message::Response(res) if res.content == ResponseContent::Success { content: ResponseSuccess::FocusChanged }
+    ));
+
+    drop(client);
+    proxy.abort();
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use libccanvas::{
+    bindings::{
+        encode_frame, Discriminator, Encoding, FrameReader, Request, RequestContent, Response,
+        ResponseContent, ResponseSuccess,
+    },
+    client::{Client, ClientConfig},
+};
+use tokio::{
+    io::AsyncWriteExt,
+    net::{UnixListener, UnixStream},
+};
+
+/// respond to a request the way the canvas does, on a new connection to the listener socket
+async fn respond(listener_socket: &Path, req: &Request, content: ResponseContent) {
+    let res = Response {
+        content,
+        id: 0,
+        request: Some(req.id()),
+    };
+    let mut stream = UnixStream::connect(listener_socket).await.unwrap();
+    stream
+        .write_all(&encode_frame(&res).unwrap())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn listener_is_set_again_after_reconnecting() {
+    let dir = std::env::temp_dir().join(format!("libccanvas-reconnect-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let requests = UnixListener::bind(dir.join("requests.sock")).unwrap();
+    let listener_socket = dir.join("listen.sock");
+    let config = ClientConfig {
+        listener_socket: listener_socket.clone(),
+        request_socket: dir.join("requests.sock"),
+        request_timeout: None,
+    };
+
+    let client = tokio::spawn(async move {
+        let client = Client::new(config).await.unwrap();
+        client.focus_at(Discriminator::master()).await.unwrap();
+    });
+
+    let (stream, _) = requests.accept().await.unwrap();
+    let mut first = FrameReader::new(stream);
+    let set_socket: Request = first.next().await.unwrap().unwrap();
+    // the canvas closes the connection before the client sends anything else
+    drop(first);
+    respond(
+        &listener_socket,
+        &set_socket,
+        ResponseContent::Success {
+            content: ResponseSuccess::ListenerSet {
+                encoding: Encoding::Json,
+                protocol: None,
+                capabilities: Vec::new(),
+            },
+        },
+    )
+    .await;
+
+    let (stream, _) = requests.accept().await.unwrap();
+    let mut second = FrameReader::new(stream);
+    let set_again: Request = second.next().await.unwrap().unwrap();
+    assert_eq!(set_again.content, set_socket.content);
+    let focus: Request = second.next().await.unwrap().unwrap();
+    assert_eq!(focus.content, RequestContent::FocusAt);
+    respond(
+        &listener_socket,
+        &focus,
+        ResponseContent::Success {
+            content: ResponseSuccess::FocusChanged,
+        },
+    )
+    .await;
+
+    client.await.unwrap();
+}
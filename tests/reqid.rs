@@ -0,0 +1,67 @@
+use std::{collections::HashSet, sync::Arc};
+
+use libccanvas::bindings::{Discriminator, Request, RequestContent, RequestIds};
+
+#[test]
+fn concurrent_senders_never_reuse_ids() {
+    let ids = Arc::new(RequestIds::new());
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let ids = ids.clone();
+            std::thread::spawn(move || {
+                (0..10_000)
+                    .map(|_| {
+                        Request::new(Discriminator::default(), RequestContent::FocusAt, &ids).id()
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for thread in threads {
+        for id in thread.join().unwrap() {
+            assert!(seen.insert(id), "id {id} was handed out twice");
+        }
+    }
+    assert_eq!(seen.len(), 80_000);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_tasks_never_reuse_ids() {
+    let ids = Arc::new(RequestIds::new());
+
+    let tasks: Vec<_> = (0..32)
+        .map(|_| {
+            let ids = ids.clone();
+            tokio::spawn(async move { (0..1000).map(|_| ids.next()).collect::<Vec<_>>() })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for task in tasks {
+        for id in task.await.unwrap() {
+            assert!(seen.insert(id), "id {id} was handed out twice");
+        }
+    }
+}
+
+#[test]
+fn allocators_are_independent() {
+    let first = RequestIds::new();
+    let second = RequestIds::new();
+
+    assert_eq!(first.next(), 1);
+    assert_eq!(first.next(), 2);
+    assert_eq!(second.next(), 1);
+}
+
+#[test]
+fn wraps_around_without_handing_out_zero() {
+    let ids = RequestIds::starting_after(u32::MAX - 1);
+
+    assert_eq!(ids.next(), u32::MAX);
+    assert_eq!(ids.next(), 1);
+    assert_eq!(ids.next(), 2);
+}
@@ -0,0 +1,75 @@
+use libccanvas::{
+    bindings::{Discriminator, ResponseContent, ResponseError, ResponseSuccess, Subscription},
+    client::{Client, ClientError},
+    testing::MockCanvas,
+};
+
+#[tokio::test]
+async fn successes_are_typed() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = Client::new(canvas.config()).await.unwrap();
+
+    assert_eq!(
+        client
+            .spawn("label".to_string(), "command".to_string(), Vec::new())
+            .await
+            .unwrap(),
+        Discriminator::new(vec![1, 2])
+    );
+    assert_eq!(
+        client
+            .new_space(Discriminator::master(), "space".to_string())
+            .await
+            .unwrap(),
+        Discriminator::new(vec![1, 3])
+    );
+    client.subscribe(Subscription::AllKeyPresses).await.unwrap();
+    client
+        .message(Discriminator::master(), "hello".to_string())
+        .await
+        .unwrap();
+    client.focus_at(Discriminator::master()).await.unwrap();
+
+    client.setchar(0, 0, 'a');
+    client.renderall().await.unwrap();
+}
+
+#[tokio::test]
+async fn failures_are_errors() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    canvas.script(ResponseContent::Error {
+        content: ResponseError::SpawnFailed,
+    });
+    assert!(matches!(
+        client
+            .spawn("label".to_string(), "missing".to_string(), Vec::new())
+            .await,
+        Err(ClientError::Response(ResponseError::SpawnFailed))
+    ));
+
+    canvas.script(ResponseContent::Undelivered);
+    assert!(matches!(
+        client
+            .message(Discriminator::new(vec![1, 9]), "hello".to_string())
+            .await,
+        Err(ClientError::Undelivered)
+    ));
+}
+
+#[tokio::test]
+async fn unexpected_successes_are_errors() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let client = Client::new(canvas.config()).await.unwrap();
+
+    canvas.script(ResponseContent::Success {
+        content: ResponseSuccess::Rendered,
+    });
+    assert!(matches!(
+        client.focus_at(Discriminator::new(vec![1, 9])).await,
+        Err(ClientError::UnexpectedResponse(ResponseContent::Success {
+            content: ResponseSuccess::Rendered
+        }))
+    ));
+}
@@ -0,0 +1,80 @@
+use libccanvas::{
+    bindings::{
+        Discriminator, Encoding, EventVariant, RequestContent, ResponseContent, ResponseSuccess,
+    },
+    client::{Client, ClientConfig},
+    server::Server,
+};
+
+#[tokio::test]
+async fn serves_a_client() {
+    let dir = std::env::temp_dir().join(format!("libccanvas-server-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut server = Server::bind(dir.join("requests.sock")).await.unwrap();
+    let config = ClientConfig {
+        listener_socket: dir.join("listen.sock"),
+        request_socket: server.request_socket().to_path_buf(),
+        request_timeout: None,
+    };
+
+    let client = tokio::spawn(async move {
+        let mut client = Client::new(config).await.unwrap();
+        let discrim = client
+            .spawn("label".to_string(), "command".to_string(), Vec::new())
+            .await
+            .unwrap();
+        client.recv().await.unwrap().done(false).unwrap();
+        discrim
+    });
+
+    let set_socket = server.accept().await.unwrap();
+    assert!(matches!(
+        set_socket.request.content,
+        RequestContent::SetSocket { .. }
+    ));
+    assert_eq!(
+        set_socket.peer.listener_socket(),
+        Some(dir.join("listen.sock"))
+    );
+    set_socket
+        .respond(ResponseContent::Success {
+            content: ResponseSuccess::ListenerSet {
+                encoding: Encoding::Json,
+                protocol: None,
+                capabilities: Vec::new(),
+            },
+        })
+        .await
+        .unwrap();
+
+    let spawn = server.accept().await.unwrap();
+    assert!(matches!(
+        spawn.request.content,
+        RequestContent::Spawn { .. }
+    ));
+    spawn
+        .respond(ResponseContent::Success {
+            content: ResponseSuccess::Spawned {
+                discrim: Discriminator::new(vec![1, 5]),
+            },
+        })
+        .await
+        .unwrap();
+
+    let event = spawn.peer.send_event(EventVariant::Focused).await.unwrap();
+    assert_eq!(server.confirmations().pending(), vec![event]);
+
+    let confirm = server.accept().await.unwrap();
+    assert_eq!(
+        confirm.request.content,
+        RequestContent::ConfirmRecieve {
+            id: event,
+            pass: false
+        }
+    );
+    assert_eq!(server.confirmations().get(event), Some(false));
+    assert!(!server.confirmations().wait(event).await);
+    assert!(server.confirmations().pending().is_empty());
+
+    assert_eq!(client.await.unwrap(), Discriminator::new(vec![1, 5]));
+}
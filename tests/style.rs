@@ -0,0 +1,86 @@
+mod common;
+
+use libccanvas::{
+    bindings::{Attributes, Capability, Colour, RenderRequest, Style},
+    client::{Surface, TextStyle},
+    testing::MockCanvas,
+};
+
+fn bold_red() -> Style {
+    Style::new()
+        .with_colours(Colour::Red, Colour::Reset)
+        .with_attributes(Attributes::BOLD | Attributes::UNDERLINE)
+}
+
+#[test]
+fn attributes_combine() {
+    let mut attributes = Attributes::BOLD | Attributes::ITALIC;
+    assert!(attributes.contains(Attributes::BOLD));
+    assert!(!attributes.contains(Attributes::BOLD | Attributes::DIM));
+
+    attributes |= Attributes::DIM;
+    assert_eq!(attributes.bits(), 0b111);
+    assert!(Attributes::NONE.is_empty());
+    assert_eq!(Attributes::from_bits(0b1000), Attributes::UNDERLINE);
+}
+
+#[tokio::test]
+async fn sends_styled_chars_when_supported() {
+    let canvas = MockCanvas::new()
+        .await
+        .unwrap()
+        .with_capabilities(vec![Capability::STYLED_CHARS]);
+    let mut client = common::connect(&canvas).await;
+    assert!(client.supports(&Capability::STYLED_CHARS));
+
+    client.setcharstyled(0, 0, 'a', bold_red());
+    client.renderall().await.unwrap();
+
+    assert_eq!(
+        common::renders(&canvas).pop().unwrap(),
+        vec![RenderRequest::setchar_styled(0, 0, 'a', bold_red())]
+    );
+    assert!(canvas
+        .screen()
+        .to_ansi()
+        .starts_with("\x1b[0;1;4;31ma\x1b[0m"));
+}
+
+#[tokio::test]
+async fn falls_back_to_colours() {
+    let canvas = MockCanvas::new().await.unwrap();
+    let mut client = common::connect(&canvas).await;
+    assert!(!client.supports(&Capability::STYLED_CHARS));
+
+    client.setcharstyled(0, 0, 'a', bold_red());
+    // reversed colours are swapped, so they still stand out
+    client.setcharstyled(1, 0, 'b', bold_red().with_attributes(Attributes::REVERSE));
+    client.renderall().await.unwrap();
+
+    assert_eq!(
+        common::renders(&canvas).pop().unwrap(),
+        vec![
+            RenderRequest::setchar_coloured(0, 0, 'a', Colour::Red, Colour::Reset),
+            RenderRequest::setchar_coloured(1, 0, 'b', Colour::Reset, Colour::Red),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn prints_styled_text() {
+    let canvas = MockCanvas::new()
+        .await
+        .unwrap()
+        .with_capabilities(vec![Capability::STYLED_CHARS]);
+    let mut client = common::connect(&canvas).await;
+
+    client.print_styled(
+        0,
+        0,
+        "hi",
+        &TextStyle::new().with_style(Style::new().with_attributes(Attributes::ITALIC)),
+    );
+    client.renderall().await.unwrap();
+
+    assert!(canvas.screen().to_ansi().starts_with("\x1b[0;3mhi\x1b[0m"));
+}
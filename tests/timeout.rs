@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use libccanvas::{
+    bindings::{Discriminator, Subscription},
+    client::{Client, ClientError},
+    testing::{MockCanvas, Reply},
+};
+
+#[tokio::test]
+async fn default_timeout_applies() {
+    let canvas = MockCanvas::new().await.unwrap();
+    canvas.script(Reply::Ignore);
+    let client = Client::new(
+        canvas
+            .config()
+            .with_request_timeout(Some(Duration::from_millis(50))),
+    )
+    .await
+    .unwrap();
+
+    let start = Instant::now();
+    let res = client.subscribe(Subscription::AllKeyPresses).await;
+    assert!(matches!(
+        res,
+        Err(ClientError::Timeout { after, .. }) if after == Duration::from_millis(50)
+    ));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn per_call_timeout_overrides_default() {
+    let canvas = MockCanvas::new().await.unwrap();
+    canvas.script(Reply::Ignore);
+    let client = Client::new(canvas.config().with_request_timeout(None))
+        .await
+        .unwrap();
+
+    let res = client
+        .with_timeout(Some(Duration::from_millis(20)))
+        .subscribe(Subscription::AllKeyPresses)
+        .await;
+    assert!(matches!(
+        res,
+        Err(ClientError::Timeout { after, .. }) if after == Duration::from_millis(20)
+    ));
+    assert_eq!(client.timeout(), None);
+}
+
+#[tokio::test]
+async fn requests_still_work_after_a_timeout() {
+    let canvas = MockCanvas::new().await.unwrap();
+    canvas.script(Reply::Ignore);
+    let client = Client::new(
+        canvas
+            .config()
+            .with_request_timeout(Some(Duration::from_millis(50))),
+    )
+    .await
+    .unwrap();
+
+    assert!(client.subscribe(Subscription::AllKeyPresses).await.is_err());
+    assert!(client.focus_at(Discriminator::default()).await.is_ok());
+}
@@ -0,0 +1,113 @@
+use libccanvas::bindings::{
+    EventVariant, Response, ResponseContent, ResponseError, ResponseSuccess,
+};
+use serde_json::json;
+
+#[test]
+fn unknown_events_keep_their_content() {
+    let res: Response = serde_json::from_value(json!({
+        "content": {
+            "type": "event",
+            "content": { "type": "paste", "text": "hello" }
+        },
+        "id": 7,
+    }))
+    .unwrap();
+
+    assert_eq!(res.id, 7);
+    match res.content {
+        ResponseContent::Event {
+            content: EventVariant::Unknown(raw),
+        } => {
+            assert_eq!(raw.kind(), Some("paste"));
+            assert_eq!(raw["text"], "hello");
+        }
+        content => panic!("expected an unknown event, got {content:?}"),
+    }
+}
+
+#[test]
+fn unknown_errors_and_successes_keep_their_content() {
+    let res: Response = serde_json::from_value(json!({
+        "content": {
+            "type": "error",
+            "content": { "type": "permission denied", "reason": "sandboxed" }
+        },
+        "id": 0,
+        "request": 3,
+    }))
+    .unwrap();
+    assert!(matches!(
+        res.content,
+        ResponseContent::Error { content: ResponseError::Unknown(raw) }
+            if raw.kind() == Some("permission denied") && raw["reason"] == "sandboxed"
+    ));
+
+    let res: Response = serde_json::from_value(json!({
+        "content": {
+            "type": "success",
+            "content": { "type": "layer created", "layer": 2 }
+        },
+        "id": 0,
+        "request": 4,
+    }))
+    .unwrap();
+    assert!(matches!(
+        res.content,
+        ResponseContent::Success { content: ResponseSuccess::Unknown(raw) }
+            if raw.kind() == Some("layer created") && raw["layer"] == 2
+    ));
+}
+
+#[test]
+fn known_variants_are_not_unknown() {
+    let event: EventVariant =
+        serde_json::from_value(json!({ "type": "resize", "width": 80, "height": 24 })).unwrap();
+    assert_eq!(
+        event,
+        EventVariant::Resize {
+            width: 80,
+            height: 24
+        }
+    );
+
+    let success: ResponseSuccess = serde_json::from_value(json!({ "type": "rendered" })).unwrap();
+    assert_eq!(success, ResponseSuccess::Rendered);
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn unknown_events_decode_from_msgpack() {
+    use libccanvas::bindings::Encoding;
+
+    let bytes = rmp_serde::to_vec_named(&json!({
+        "content": {
+            "type": "event",
+            "content": { "type": "paste", "text": "hello" }
+        },
+        "id": 7,
+    }))
+    .unwrap();
+    let res: Response = Encoding::MessagePack.decode(&bytes).unwrap();
+    assert!(matches!(
+        res.content,
+        ResponseContent::Event { content: EventVariant::Unknown(raw) } if raw["text"] == "hello"
+    ));
+}
+
+#[test]
+fn known_types_with_bad_fields_are_unknown() {
+    let event: EventVariant =
+        serde_json::from_value(json!({ "type": "resize", "width": "wide" })).unwrap();
+    assert!(matches!(
+        event,
+        EventVariant::Unknown(raw) if raw.kind() == Some("resize") && raw["width"] == "wide"
+    ));
+
+    let success: ResponseSuccess =
+        serde_json::from_value(json!({ "type": "space created", "discrim": 3 })).unwrap();
+    assert!(matches!(
+        success,
+        ResponseSuccess::Unknown(raw) if raw.kind() == Some("space created")
+    ));
+}
@@ -0,0 +1,99 @@
+use libccanvas::{
+    bindings::{Colour, CursorStyle, RenderRequest},
+    client::Client,
+    testing::{MockCanvas, VirtualScreen},
+};
+
+#[test]
+fn applies_render_requests() {
+    let mut screen = VirtualScreen::new(6, 3);
+    screen.apply(&RenderRequest::RenderMultiple {
+        tasks: vec![
+            RenderRequest::setchar(0, 0, 'h'),
+            RenderRequest::setchar(1, 0, 'i'),
+            RenderRequest::setchar_coloured(2, 1, '#', Colour::Red, Colour::Reset),
+            // off screen, ignored
+            RenderRequest::setchar(6, 0, 'x'),
+            RenderRequest::setchar(0, 3, 'x'),
+            RenderRequest::HideCursor,
+            RenderRequest::setcursor(CursorStyle::SteadyBar),
+        ],
+    });
+
+    assert_eq!(screen.to_plain(), "hi\n  #\n");
+    assert_eq!(screen.cell(2, 1).unwrap().fg, Colour::Red);
+    assert!(screen.cell(6, 0).is_none());
+    assert!(!screen.cursor_visible());
+    assert_eq!(screen.cursor_style(), Some(CursorStyle::SteadyBar));
+}
+
+#[test]
+fn wide_characters_cover_the_next_cell() {
+    let mut screen = VirtualScreen::new(6, 2);
+    screen.apply(&RenderRequest::RenderMultiple {
+        tasks: vec![
+            RenderRequest::setchar(0, 0, '你'),
+            RenderRequest::setchar(2, 0, '好'),
+            RenderRequest::setchar(4, 0, '!'),
+            RenderRequest::setchar(0, 1, '你'),
+            RenderRequest::setchar(2, 1, '好'),
+        ],
+    });
+    assert_eq!(screen.to_plain(), "你好!\n你好");
+    assert!(screen.is_continuation(1, 0));
+    assert!(!screen.is_continuation(2, 0));
+
+    // overwriting either half of a wide character erases all of it
+    screen.apply(&RenderRequest::setchar(1, 1, 'a'));
+    screen.apply(&RenderRequest::setchar(2, 1, 'b'));
+    assert_eq!(screen.to_plain(), "你好!\n ab");
+    assert!(!screen.is_continuation(3, 1));
+}
+
+#[test]
+fn ansi_snapshots_only_change_colours_when_needed() {
+    let mut screen = VirtualScreen::new(4, 2);
+    screen.apply(&RenderRequest::setchar_coloured(
+        0,
+        0,
+        'a',
+        Colour::Red,
+        Colour::Reset,
+    ));
+    screen.apply(&RenderRequest::setchar_coloured(
+        1,
+        0,
+        'b',
+        Colour::Red,
+        Colour::Reset,
+    ));
+    screen.apply(&RenderRequest::setchar_coloured(
+        2,
+        1,
+        'c',
+        Colour::Rgb {
+            red: 1,
+            green: 2,
+            blue: 3,
+        },
+        Colour::LightBlue,
+    ));
+
+    assert_eq!(
+        screen.to_ansi(),
+        "\x1b[0;31mab\x1b[0m  \n  \x1b[0;38;2;1;2;3;104mc\x1b[0m "
+    );
+}
+
+#[tokio::test]
+async fn mock_canvas_renders_to_its_screen() {
+    let canvas = MockCanvas::new().await.unwrap().with_screen_size(10, 2);
+    let mut client = Client::new(canvas.config()).await.unwrap();
+
+    for (x, c) in "snake".chars().enumerate() {
+        client.setchar(x as u32, 1, c);
+    }
+    client.renderall().await.unwrap();
+
+    assert_eq!(canvas.screen().to_plain(), "\nsnake");
+}